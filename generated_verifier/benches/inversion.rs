@@ -0,0 +1,35 @@
+//! Times the two field inversion strategies behind `field::inv_mod_auto`.
+//!
+//! Runs natively, not on PolkaVM, so it only ranks the strategies by the work
+//! they do; re-check on the target before relying on absolute numbers.
+//!
+//!     cargo bench --bench inversion
+
+use generated_verifier::field::{inv_mod, inv_mod_gcd, random_nonzero_fr, Fr};
+use rand::{rngs::StdRng, SeedableRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const INPUTS: usize = 256;
+const ROUNDS: usize = 4;
+
+fn time(inputs: &[Fr], invert: fn(Fr) -> Fr) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for &a in inputs {
+            black_box(invert(black_box(a)));
+        }
+    }
+    start.elapsed() / (ROUNDS * inputs.len()) as u32
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(0x1640);
+    let inputs: Vec<Fr> = (0..INPUTS).map(|_| random_nonzero_fr(&mut rng)).collect();
+
+    let fermat = time(&inputs, inv_mod);
+    let gcd = time(&inputs, inv_mod_gcd);
+    println!("inv_mod (Fermat):  {fermat:?} per inversion");
+    println!("inv_mod_gcd:       {gcd:?} per inversion");
+    println!("fermat / gcd:      {:.0}x", fermat.as_secs_f64() / gcd.as_secs_f64());
+}
@@ -0,0 +1,112 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Range checks on raw VK and proof bytes, for catching serialization bugs
+//! on the prover side before anything cryptographic runs.
+
+use primitive_types::U256;
+use crate::errors::{VerifierError, VerifierResult};
+use crate::field::MODULUS;
+use crate::honk_structs::{unpack_coord, BB_VK_HEADER_FIELDS};
+
+/// Slice both buffers into 32-byte big-endian fields and report the first one
+/// that is not canonical, with its byte offset into its own buffer.
+///
+/// The VK is in bb's layout (see `VerificationKey::from_bb_fields`): its header
+/// fields and every proof field must be below the scalar modulus. After the
+/// header each `(lo, hi)` limb pair must form a coordinate below the base
+/// modulus, reported at the offset of `lo`.
+pub fn audit_ranges(vk_bytes: &[u8], proof_bytes: &[u8]) -> VerifierResult<()> {
+    if !vk_bytes.len().is_multiple_of(32) {
+        return Err(VerifierError::InvalidVerificationKey);
+    }
+    if !proof_bytes.len().is_multiple_of(32) {
+        return Err(VerifierError::InvalidProofFormat);
+    }
+
+    let vk_fields: ink::prelude::vec::Vec<U256> = vk_bytes.chunks_exact(32).map(U256::from_big_endian).collect();
+    let out_of_range = |i: usize| VerifierError::VkFieldOutOfRange { offset: (i * 32) as u32 };
+    let header = vk_fields.len().min(BB_VK_HEADER_FIELDS);
+    if let Some(i) = vk_fields[..header].iter().position(|field| *field >= MODULUS) {
+        return Err(out_of_range(i));
+    }
+    for (k, limbs) in vk_fields[header..].chunks(2).enumerate() {
+        let i = header + 2 * k;
+        match limbs {
+            [lo, hi] => {
+                unpack_coord(*lo, *hi).map_err(|_| out_of_range(i))?;
+            }
+            [lone] if *lone >= MODULUS => return Err(out_of_range(i)),
+            _ => {}
+        }
+    }
+
+    for (i, field) in proof_bytes.chunks_exact(32).enumerate() {
+        if U256::from_big_endian(field) >= MODULUS {
+            return Err(VerifierError::ProofFieldOutOfRange { offset: (i * 32) as u32 });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::to_bytes_be;
+
+    use crate::curve::Q;
+    use crate::honk_structs::{pack_coord, BB_VK_FIELD_COUNT};
+
+    fn fields(values: &[U256]) -> ink::prelude::vec::Vec<u8> {
+        values.iter().flat_map(|v| to_bytes_be(*v)).collect()
+    }
+
+    /// A VK in bb's layout whose first commitment's x is `x`, all else zero
+    fn vk_with_x(x: U256) -> ink::prelude::vec::Vec<u8> {
+        let mut values = [U256::zero(); BB_VK_FIELD_COUNT];
+        values[0] = U256::from(32);
+        (values[BB_VK_HEADER_FIELDS], values[BB_VK_HEADER_FIELDS + 1]) = pack_coord(x);
+        fields(&values)
+    }
+
+    #[test]
+    fn test_first_out_of_range_proof_field() {
+        let vk = vk_with_x(Q - 1);
+        let mut proof = fields(&[U256::from(7); 6]);
+        proof[3 * 32..4 * 32].copy_from_slice(&to_bytes_be(MODULUS));
+        proof[5 * 32..].copy_from_slice(&[0xff; 32]);
+
+        assert_eq!(
+            audit_ranges(&vk, &proof),
+            Err(VerifierError::ProofFieldOutOfRange { offset: 96 })
+        );
+    }
+
+    #[test]
+    fn test_vk_bounds() {
+        let proof = fields(&[U256::one()]);
+        let x_offset = (BB_VK_HEADER_FIELDS * 32) as u32;
+
+        // Coordinates may exceed the scalar modulus, header fields may not
+        assert_eq!(audit_ranges(&vk_with_x(MODULUS), &proof), Ok(()));
+
+        let mut vk = vk_with_x(U256::one());
+        vk[32..64].copy_from_slice(&to_bytes_be(MODULUS));
+        assert_eq!(audit_ranges(&vk, &proof), Err(VerifierError::VkFieldOutOfRange { offset: 32 }));
+
+        let vk = vk_with_x(Q);
+        assert_eq!(audit_ranges(&vk, &proof), Err(VerifierError::VkFieldOutOfRange { offset: x_offset }));
+
+        // A limb wider than 136 bits, even if the coordinate it would form is small
+        let mut vk = vk_with_x(U256::one());
+        vk[x_offset as usize + 14] = 1;
+        assert_eq!(audit_ranges(&vk, &proof), Err(VerifierError::VkFieldOutOfRange { offset: x_offset }));
+
+        assert_eq!(audit_ranges(&vk[..40], &proof), Err(VerifierError::InvalidVerificationKey));
+    }
+
+    #[test]
+    fn test_fixture_vk_is_canonical() {
+        assert_eq!(audit_ranges(include_bytes!("../tests/fixtures/vk"), &[]), Ok(()));
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! UltraHonk circuit-independent constants, shared by every module.
+//! The asserts below pin the relationships the verifier relies on, so
+//! changing one constant without the others fails the build.
+
+// Proofs are padded to this many sumcheck rounds / Gemini folds
+pub const CONST_PROOF_SIZE_LOG_N: usize = 28;
+// Evaluations per sumcheck round univariate
+pub const BATCHED_RELATION_PARTIAL_LENGTH: usize = 8;
+// Highest degree of the batched relations (including the pow factor) in
+// any one variable
+pub const MAX_RELATION_DEGREE: usize = 7;
+// Polynomial evaluations in the proof: unshifted then shifted
+pub const NUMBER_OF_ENTITIES: usize = 40;
+pub const NUMBER_UNSHIFTED: usize = 35;
+pub const NUMBER_TO_BE_SHIFTED: usize = 5;
+pub const NUMBER_OF_SUBRELATIONS: usize = 26;
+// The first subrelation is not scaled by an alpha
+pub const NUMBER_OF_ALPHAS: usize = 25;
+// Selector, permutation, table and Lagrange commitments in the VK
+pub const NUMBER_OF_VK_COMMITMENTS: usize = 27;
+// w1..w4, z_perm, lookup_inverses, lookup_read_counts, lookup_read_tags
+pub const NUMBER_OF_WITNESS_COMMITMENTS: usize = 8;
+// Trailing public inputs holding a recursive proof's pairing-point accumulator
+pub const PAIRING_POINTS_SIZE: usize = 16;
+
+// Every entity is either unshifted or shifted
+const _: () = assert!(NUMBER_UNSHIFTED + NUMBER_TO_BE_SHIFTED == NUMBER_OF_ENTITIES);
+
+// Unshifted evaluations are the VK commitments followed by the witness
+// commitments, in the order Shplemini batches them
+const _: () = assert!(NUMBER_OF_VK_COMMITMENTS + NUMBER_OF_WITNESS_COMMITMENTS == NUMBER_UNSHIFTED);
+
+// Shifted entities are w1..w4 and z_perm, all witness commitments
+const _: () = assert!(NUMBER_TO_BE_SHIFTED <= NUMBER_OF_WITNESS_COMMITMENTS);
+
+// One alpha per subrelation after the first
+const _: () = assert!(NUMBER_OF_ALPHAS + 1 == NUMBER_OF_SUBRELATIONS);
+
+// Relation families: arithmetic 2, permutation 2, lookup 2, delta range 4,
+// elliptic 2, auxiliary 6, poseidon external 4, poseidon internal 4
+const _: () = assert!(2 + 2 + 2 + 4 + 2 + 6 + 4 + 4 == NUMBER_OF_SUBRELATIONS);
+
+// Two G1 points, two coordinates each, four limbs per coordinate
+const _: () = assert!(PAIRING_POINTS_SIZE == 2 * 2 * 4);
+
+// A round univariate of degree d is only determined by d + 1 evaluations
+const _: () = assert!(BATCHED_RELATION_PARTIAL_LENGTH > MAX_RELATION_DEGREE);
+
+// BN254's scalar field has 2-adicity 28, so no evaluation domain is larger
+const _: () = assert!(CONST_PROOF_SIZE_LOG_N > 0 && CONST_PROOF_SIZE_LOG_N <= 28);
@@ -0,0 +1,211 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use primitive_types::{U256, U512};
+use crate::fq2::Fq2;
+use crate::honk_structs::{G1Point, G2Point};
+
+// BN254 base field modulus
+pub const Q: U256 = U256([
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+// BN254 curve coefficient: y^2 = x^3 + 3
+const B: u64 = 3;
+
+// Twist coefficient for G2: y^2 = x^3 + 3 / (9 + u)
+const TWIST_B: Fq2 = Fq2::new(
+    U256([0x3267e6dc24a138e5, 0xb5b4c5e559dbefa3, 0x81be18991be06ac3, 0x2b149d40ceb8aaae]),
+    U256([0xe4a2bd0685c315d2, 0xa74fa084e52d1852, 0xcd2cafadeed8fdf4, 0x009713b03af0fed4]),
+);
+
+/// Generator of G2, `[1]_2`
+pub const G2_GEN: G2Point = G2Point {
+    x_c0: U256([0x46debd5cd992f6ed, 0x674322d4f75edadd, 0x426a00665e5c4479, 0x1800deef121f1e76]),
+    x_c1: U256([0x97e485b7aef312c2, 0xf1aa493335a9e712, 0x7260bfb731fb5d25, 0x198e9393920d483a]),
+    y_c0: U256([0x4ce6cc0166fa7daa, 0xe3d1e7690c43d37b, 0x4aab71808dcb408f, 0x12c85ea5db8c6deb]),
+    y_c1: U256([0x55acdadcd122975b, 0xbc4b313370b38ef3, 0xec9e99ad690c3395, 0x090689d0585ff075]),
+};
+
+/// `[x]_2` from the SRS, the G2 half of the KZG opening check
+pub const G2_X: G2Point = G2Point {
+    x_c0: U256([0x7e231fec938883b0, 0x9f5944073b32078b, 0xbc89b5b398b5974e, 0x0118c4d5b837bcc2]),
+    x_c1: U256([0x4efe30fac09383c1, 0xea51d87a358e038b, 0xe7ff4e580791dee8, 0x260e01b251f6f1c7]),
+    y_c0: U256([0x854a87d4dacc5e55, 0x11e6dd3f96e6cea2, 0x56475b4214e5615e, 0x22febda3c0c0632a]),
+    y_c1: U256([0xee413c80da6a5fe4, 0x9cf2a04641f99ba4, 0xd25156c1bb9a7285, 0x04fc6369f7110fe3]),
+};
+
+/// Multiply two base field elements modulo q
+pub fn mul_q(a: U256, b: U256) -> U256 {
+    let product: U512 = a.full_mul(b) % U512::from(Q);
+    U256::try_from(product).expect("reduced value fits in 256 bits")
+}
+
+/// Add two base field elements modulo q
+pub fn add_q(a: U256, b: U256) -> U256 {
+    let (sum, overflow) = a.overflowing_add(b);
+    if overflow || sum >= Q {
+        sum.overflowing_sub(Q).0
+    } else {
+        sum
+    }
+}
+
+/// Subtract two base field elements modulo q
+pub fn sub_q(a: U256, b: U256) -> U256 {
+    if a >= b {
+        a - b
+    } else {
+        Q - (b - a)
+    }
+}
+
+/// The point at infinity is encoded as (0, 0)
+pub fn is_infinity(p: &G1Point) -> bool {
+    p.x.is_zero() && p.y.is_zero()
+}
+
+/// Negate a G1 point: (x, y) -> (x, q - y)
+pub fn negate(p: &G1Point) -> G1Point {
+    if is_infinity(p) {
+        return *p;
+    }
+    G1Point { x: p.x, y: Q - p.y }
+}
+
+/// Check that a G1 point lies on BN254 (or is the point at infinity, encoded as (0, 0))
+pub fn is_on_curve(p: &G1Point) -> bool {
+    if is_infinity(p) {
+        return true;
+    }
+    if p.x >= Q || p.y >= Q {
+        return false;
+    }
+    let lhs = mul_q(p.y, p.y);
+    let rhs = add_q(mul_q(mul_q(p.x, p.x), p.x), U256::from(B));
+    lhs == rhs
+}
+
+/// Check that a G2 point lies on the twist (or is the point at infinity, all zeros)
+pub fn g2_is_on_curve(p: &G2Point) -> bool {
+    let coordinates = [p.x_c0, p.x_c1, p.y_c0, p.y_c1];
+    if coordinates.iter().all(U256::is_zero) {
+        return true;
+    }
+    if coordinates.iter().any(|c| *c >= Q) {
+        return false;
+    }
+    let x = Fq2::new(p.x_c0, p.x_c1);
+    let y = Fq2::new(p.y_c0, p.y_c1);
+    y.square() == x.square().mul(&x).add(&TWIST_B)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::MODULUS;
+
+    /// Affine G2 point, `None` for infinity
+    type G2Affine = Option<(Fq2, Fq2)>;
+
+    fn g2_add(p: G2Affine, q: G2Affine) -> G2Affine {
+        let ((x1, y1), (x2, y2)) = match (p, q) {
+            (None, q) => return q,
+            (p, None) => return p,
+            (Some(p), Some(q)) => (p, q),
+        };
+        let slope = if x1 == x2 {
+            if y1 != y2 || y1.is_zero() {
+                return None;
+            }
+            let three_x_squared = x1.square().mul(&Fq2::new(U256::from(3), U256::zero()));
+            three_x_squared.mul(&y1.add(&y1).inv())
+        } else {
+            y2.sub(&y1).mul(&x2.sub(&x1).inv())
+        };
+        let x3 = slope.square().sub(&x1).sub(&x2);
+        let y3 = slope.mul(&x1.sub(&x3)).sub(&y1);
+        Some((x3, y3))
+    }
+
+    fn g2_mul(p: &G2Point, scalar: U256) -> G2Affine {
+        let base = Some((Fq2::new(p.x_c0, p.x_c1), Fq2::new(p.y_c0, p.y_c1)));
+        let mut result = None;
+        for i in (0..scalar.bits()).rev() {
+            result = g2_add(result, result);
+            if scalar.bit(i) {
+                result = g2_add(result, base);
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_generator_on_curve() {
+        let g = G1Point { x: U256::one(), y: U256::from(2) };
+        assert!(is_on_curve(&g));
+
+        // -G = (1, q - 2)
+        let neg_g = G1Point { x: U256::one(), y: Q - U256::from(2) };
+        assert!(is_on_curve(&neg_g));
+    }
+
+    #[test]
+    fn test_off_curve() {
+        let p = G1Point { x: U256::one(), y: U256::from(3) };
+        assert!(!is_on_curve(&p));
+
+        // Non-canonical coordinates are rejected even if congruent to a valid point
+        let p = G1Point { x: U256::one() + Q, y: U256::from(2) };
+        assert!(!is_on_curve(&p));
+    }
+
+    #[test]
+    fn test_infinity() {
+        assert!(is_on_curve(&G1Point::default()));
+        assert_eq!(negate(&G1Point::default()), G1Point::default());
+    }
+
+    #[test]
+    fn test_negate() {
+        let g = G1Point { x: U256::one(), y: U256::from(2) };
+        let neg_g = negate(&g);
+        assert!(is_on_curve(&neg_g));
+        assert_eq!(add_q(g.y, neg_g.y), U256::zero());
+        assert_eq!(negate(&neg_g), g);
+    }
+
+    #[test]
+    fn test_g2_encoding() {
+        let bytes = G2_GEN.to_bytes();
+        // x.c1 leads, as in the Solidity verifier's pairing input
+        assert_eq!(bytes[..4], [0x19, 0x8e, 0x93, 0x93]);
+        assert_eq!(bytes[32..36], [0x18, 0x00, 0xde, 0xef]);
+        assert_eq!(G2_X.to_bytes()[..4], [0x26, 0x0e, 0x01, 0xb2]);
+    }
+
+    #[test]
+    fn test_g2_constants_on_curve_and_in_subgroup() {
+        for point in [G2_GEN, G2_X] {
+            assert!(g2_is_on_curve(&point));
+            // r * P == O, so the point generates the order-r subgroup
+            assert_eq!(g2_mul(&point, MODULUS), None);
+            assert!(g2_mul(&point, MODULUS - U256::one()).is_some());
+        }
+    }
+
+    #[test]
+    fn test_g2_off_curve() {
+        let mut p = G2_X;
+        p.y_c1 = add_q(p.y_c1, U256::one());
+        assert!(!g2_is_on_curve(&p));
+
+        let mut p = G2_GEN;
+        p.x_c0 += Q;
+        assert!(!g2_is_on_curve(&p));
+
+        assert!(g2_is_on_curve(&G2Point::default()));
+    }
+}
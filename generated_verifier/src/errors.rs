@@ -8,10 +8,11 @@ pub enum VerifierError {
     InvalidProofFormat,
     
     /// Public inputs length doesn't match verification key
-    InvalidPublicInputsLength,
+    InvalidPublicInputsLength { expected: u32, got: u32 },
     
-    /// Public input has invalid length (should be 32 bytes)
-    InvalidPublicInputFormat,
+    /// Public input at this index is not exactly 32 bytes, or is not a valid
+    /// pairing-point limb where one is expected
+    InvalidPublicInputFormat { index: u32 },
     
     /// Sumcheck verification failed
     SumcheckFailed,
@@ -36,10 +37,51 @@ pub enum VerifierError {
     
     /// Invalid verification key
     InvalidVerificationKey,
+
+    /// VK field at this byte offset is not a canonical field element
+    VkFieldOutOfRange { offset: u32 },
+
+    /// Proof field at this byte offset is not a canonical field element
+    ProofFieldOutOfRange { offset: u32 },
+
+    /// Proof point limb wider than its share of the coordinate
+    InvalidLimb,
     
+    /// Verifier is paused by its owner
+    Paused,
+
+    /// Caller is not the contract owner
+    NotOwner,
+
+    /// Caller-supplied transcript has a zero or non-canonical challenge
+    InvalidTranscript,
+
     /// Generic error
     Other,
 }
 
 /// Result type for verifier operations
-pub type VerifierResult<T> = Result<T, VerifierError>;
\ No newline at end of file
+pub type VerifierResult<T> = Result<T, VerifierError>;
+
+/// Verification stages, in the order `verify` runs them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum VerifyStage {
+    /// Proof bytes parsed and public inputs validated against the VK
+    Parsed,
+
+    /// Every proof commitment decoded to a valid curve point
+    PointsValidated,
+
+    /// Fiat-Shamir challenges derived
+    TranscriptGenerated,
+
+    /// Sumcheck rounds and the final relation check passed
+    SumcheckPassed,
+
+    /// Shplemini batch opening reduced to a pair of G1 points
+    ShpleminiPassed,
+
+    /// Final KZG pairing check passed
+    PairingPassed,
+}
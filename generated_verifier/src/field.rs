@@ -1,6 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use primitive_types::U256;
+use crate::errors::{VerifierError, VerifierResult};
+#[cfg(any(test, feature = "std"))]
+use rand_core::RngCore;
 
 // BN254 scalar field modulus
 pub const MODULUS: U256 = U256([
@@ -12,122 +15,344 @@ pub const MODULUS: U256 = U256([
 
 pub type Fr = U256;
 
-/// Add two field elements modulo p
-pub fn add_mod(a: Fr, b: Fr) -> Fr {
-    let (sum, overflow) = a.overflowing_add(b);
-    if overflow || sum >= MODULUS {
-        sum.overflowing_sub(MODULUS).0
-    } else {
-        sum
+// -1 mod p
+pub const MINUS_ONE: Fr = U256([
+    0x43e1f593f0000000,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+// -2 mod p
+pub const MINUS_TWO: Fr = U256([
+    0x43e1f593efffffff,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+// -3 mod p
+pub const MINUS_THREE: Fr = U256([
+    0x43e1f593effffffe,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+// -1/2 mod p, i.e. (p - 1) / 2
+pub const NEG_HALF: Fr = U256([
+    0xa1f0fac9f8000000,
+    0x9419f4243cdcb848,
+    0xdc2822db40c0ac2e,
+    0x183227397098d014,
+]);
+
+/// `value < MODULUS`, for `const` assertions on hard-coded field constants
+pub const fn is_canonical(value: &U256) -> bool {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if value.0[i] != MODULUS.0[i] {
+            return value.0[i] < MODULUS.0[i];
+        }
     }
+    false
 }
 
-/// Subtract two field elements modulo p
-pub fn sub_mod(a: Fr, b: Fr) -> Fr {
-    if a >= b {
-        a - b
-    } else {
-        MODULUS - (b - a)
-    }
+const _: () = assert!(is_canonical(&MINUS_ONE));
+const _: () = assert!(is_canonical(&MINUS_TWO));
+const _: () = assert!(is_canonical(&MINUS_THREE));
+const _: () = assert!(is_canonical(&NEG_HALF));
+
+/// Modular arithmetic on canonical field elements. Every public `*_mod`
+/// function below dispatches to `Backend`, so the rest of the crate only ever
+/// sees `Fr` and is unaware of which implementation is compiled in.
+pub trait FieldBackend {
+    fn add(a: Fr, b: Fr) -> Fr;
+    fn sub(a: Fr, b: Fr) -> Fr;
+    fn mul(a: Fr, b: Fr) -> Fr;
+    fn pow(base: Fr, exp: Fr) -> Fr;
+    /// Panics if a is zero
+    fn inv(a: Fr) -> Fr;
+    fn neg(a: Fr) -> Fr;
 }
 
-/// Multiply two field elements modulo p
-/// TODO: Consider optimizing with Montgomery or Barrett reduction
-pub fn mul_mod(a: Fr, b: Fr) -> Fr {
-    // Handle zero cases early
-    if a.is_zero() || b.is_zero() {
-        return U256::zero();
+/// Hand-rolled arithmetic on `U256`, the on-chain backend
+pub struct U256Backend;
+
+/// `num-bigint` arithmetic, an independent oracle for differential checks
+/// against `U256Backend`. Std only; enabled by the `bigint-backend` feature.
+#[cfg(any(test, feature = "bigint-backend"))]
+pub struct BigIntBackend;
+
+#[cfg(not(feature = "bigint-backend"))]
+pub type Backend = U256Backend;
+#[cfg(feature = "bigint-backend")]
+pub type Backend = BigIntBackend;
+
+impl FieldBackend for U256Backend {
+    fn add(a: Fr, b: Fr) -> Fr {
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= MODULUS {
+            sum.overflowing_sub(MODULUS).0
+        } else {
+            sum
+        }
+    }
+
+    fn sub(a: Fr, b: Fr) -> Fr {
+        if a >= b {
+            a - b
+        } else {
+            MODULUS - (b - a)
+        }
+    }
+
+    /// TODO: Consider optimizing with Montgomery or Barrett reduction
+    fn mul(a: Fr, b: Fr) -> Fr {
+        // Handle zero cases early
+        if a.is_zero() || b.is_zero() {
+            return U256::zero();
+        }
+
+        // Handle one cases
+        if a == U256::one() {
+            return b;
+        }
+        if b == U256::one() {
+            return a;
+        }
+
+        // Use repeated addition for correctness
+        // For values that fit in 128 bits, we can use a more efficient method
+        let bits_a = 256 - a.leading_zeros();
+        let bits_b = 256 - b.leading_zeros();
+
+        if bits_a + bits_b <= 256 {
+            // Product won't overflow U256, can do direct multiplication and reduction
+            let product = a.saturating_mul(b);
+            return reduce_mod(product);
+        }
+
+        // For large values, use double-and-add
+        let mut result = U256::zero();
+        let mut temp = a;
+        let mut exp = b;
+
+        while !exp.is_zero() {
+            if exp & U256::one() == U256::one() {
+                result = Self::add(result, temp);
+            }
+            temp = Self::add(temp, temp);
+            exp = exp >> 1;
+        }
+
+        result
+    }
+
+    /// Binary exponentiation
+    fn pow(base: Fr, mut exp: Fr) -> Fr {
+        if exp.is_zero() {
+            return U256::one();
+        }
+
+        let mut result = U256::one();
+        let mut b = base;
+
+        while exp > U256::zero() {
+            if exp & U256::one() == U256::one() {
+                result = Self::mul(result, b);
+            }
+            b = Self::mul(b, b);
+            exp = exp >> 1;
+        }
+
+        result
+    }
+
+    fn inv(a: Fr) -> Fr {
+        inv_mod_auto(a)
     }
 
-    // Handle one cases
-    if a == U256::one() {
-        return b;
+    fn neg(a: Fr) -> Fr {
+        if a.is_zero() {
+            U256::zero()
+        } else {
+            MODULUS - a
+        }
     }
-    if b == U256::one() {
-        return a;
+}
+
+#[cfg(any(test, feature = "bigint-backend"))]
+mod bigint {
+    use super::{to_bytes_be, BigIntBackend, FieldBackend, Fr, MODULUS};
+    use num_bigint::BigUint;
+    use primitive_types::U256;
+
+    fn to_big(value: Fr) -> BigUint {
+        BigUint::from_bytes_be(&to_bytes_be(value))
     }
 
-    // Use repeated addition for correctness
-    // For values that fit in 128 bits, we can use a more efficient method
-    let bits_a = 256 - a.leading_zeros();
-    let bits_b = 256 - b.leading_zeros();
+    fn from_big(value: BigUint) -> Fr {
+        U256::from_big_endian(&value.to_bytes_be())
+    }
 
-    if bits_a + bits_b <= 256 {
-        // Product won't overflow U256, can do direct multiplication and reduction
-        let product = a.saturating_mul(b);
-        return reduce_mod(product);
+    fn modulus() -> BigUint {
+        to_big(MODULUS)
     }
 
-    // For large values, use double-and-add
-    let mut result = U256::zero();
-    let mut temp = a;
-    let mut exp = b;
+    impl FieldBackend for BigIntBackend {
+        fn add(a: Fr, b: Fr) -> Fr {
+            from_big((to_big(a) + to_big(b)) % modulus())
+        }
+
+        fn sub(a: Fr, b: Fr) -> Fr {
+            let p = modulus();
+            from_big((to_big(a) + &p - to_big(b) % &p) % p)
+        }
 
-    while !exp.is_zero() {
-        if exp & U256::one() == U256::one() {
-            result = add_mod(result, temp);
+        fn mul(a: Fr, b: Fr) -> Fr {
+            from_big(to_big(a) * to_big(b) % modulus())
+        }
+
+        fn pow(base: Fr, exp: Fr) -> Fr {
+            from_big(to_big(base).modpow(&to_big(exp), &modulus()))
+        }
+
+        fn inv(a: Fr) -> Fr {
+            let inverse = to_big(a).modinv(&modulus()).expect("Cannot invert zero");
+            from_big(inverse)
+        }
+
+        fn neg(a: Fr) -> Fr {
+            let p = modulus();
+            from_big((&p - to_big(a) % &p) % p)
         }
-        temp = add_mod(temp, temp);
-        exp = exp >> 1;
     }
+}
+
+/// Add two field elements modulo p
+pub fn add_mod(a: Fr, b: Fr) -> Fr {
+    Backend::add(a, b)
+}
 
-    result
+/// Subtract two field elements modulo p
+/// Operands are reduced first, so a non-canonical value cannot skew the result
+pub fn sub_mod(a: Fr, b: Fr) -> Fr {
+    Backend::sub(reduce_mod(a), reduce_mod(b))
+}
+
+/// Multiply two field elements modulo p
+pub fn mul_mod(a: Fr, b: Fr) -> Fr {
+    Backend::mul(a, b)
 }
 
 /// Reduce a U256 value modulo MODULUS using simple subtraction
-fn reduce_mod(mut value: U256) -> Fr {
+pub fn reduce_mod(mut value: U256) -> Fr {
     while value >= MODULUS {
         value = value - MODULUS;
     }
     value
 }
 
+/// `a == b` as field elements. Both sides are reduced first, so this holds
+/// for un-reduced inputs too, e.g. `fr_eq(5, MODULUS + 5)`
+pub fn fr_eq(a: Fr, b: Fr) -> bool {
+    reduce_mod(a) == reduce_mod(b)
+}
+
+/// Order of the canonical representatives of `a` and `b`, correct for
+/// un-reduced inputs too. Field elements have no arithmetic order; this is
+/// for deterministic sorting and comparisons against known values.
+pub fn fr_cmp(a: Fr, b: Fr) -> core::cmp::Ordering {
+    reduce_mod(a).cmp(&reduce_mod(b))
+}
+
 /// Compute modular inverse using Fermat's little theorem: a^(p-2) mod p
-/// Returns None if a is zero
+/// Panics if a is zero
 pub fn inv_mod(a: Fr) -> Fr {
+    let a = reduce_mod(a);
     assert!(!a.is_zero(), "Cannot invert zero");
 
     // a^(p-2) mod p using Fermat's little theorem
     // For BN254, p - 2 is computed directly
     let exponent = MODULUS - U256::from(2);
-    pow_mod(a, exponent)
+    U256Backend::pow(a, exponent)
 }
 
-/// Safe version of inv_mod that returns Option
-pub fn try_inv_mod(a: Fr) -> Option<Fr> {
-    if a.is_zero() {
-        return None;
+/// Modular inverse by the binary extended Euclidean algorithm.
+/// Panics if a is zero
+pub fn inv_mod_gcd(a: Fr) -> Fr {
+    // Reduce first: a multiple of p is zero too, and would never reach u = 1
+    let a = reduce_mod(a);
+    assert!(!a.is_zero(), "Cannot invert zero");
+
+    // Invariants: x1 * a = u and x2 * a = v (mod p), with x1, x2 < p
+    let mut u = a;
+    let mut v = MODULUS;
+    let mut x1 = U256::one();
+    let mut x2 = U256::zero();
+
+    // (x + p) / 2 when x is odd; x + p < 2^255 so this cannot overflow
+    let halve = |x: Fr| if x.bit(0) { (x + MODULUS) >> 1 } else { x >> 1 };
+
+    while u != U256::one() && v != U256::one() {
+        while !u.bit(0) {
+            u >>= 1;
+            x1 = halve(x1);
+        }
+        while !v.bit(0) {
+            v >>= 1;
+            x2 = halve(x2);
+        }
+        if u >= v {
+            u -= v;
+            x1 = U256Backend::sub(x1, x2);
+        } else {
+            v -= u;
+            x2 = U256Backend::sub(x2, x1);
+        }
     }
-    Some(inv_mod(a))
+
+    if u == U256::one() { x1 } else { x2 }
 }
 
-/// Compute a^exp mod p using binary exponentiation
-pub fn pow_mod(base: Fr, mut exp: Fr) -> Fr {
-    if exp.is_zero() {
-        return U256::one();
+/// Inversion strategy picked at build time for `U256Backend`.
+///
+/// Fermat (`inv_mod`) is a fixed ~254 squarings plus multiplications with a
+/// predictable instruction stream; binary GCD (`inv_mod_gcd`) needs no 256-bit
+/// multiplications but branches on every bit. With `U256Backend`'s double-and-add
+/// `mul`, `benches/inversion.rs` measures GCD at roughly 20µs against 6.7ms for
+/// Fermat natively, so GCD is the default. `inv-fermat` opts back into Fermat;
+/// `inv-gcd` selects the default explicitly and wins if both are set.
+/// Panics if a is zero
+pub fn inv_mod_auto(a: Fr) -> Fr {
+    #[cfg(all(feature = "inv-fermat", not(feature = "inv-gcd")))]
+    {
+        inv_mod(a)
     }
+    #[cfg(not(all(feature = "inv-fermat", not(feature = "inv-gcd"))))]
+    {
+        inv_mod_gcd(a)
+    }
+}
 
-    let mut result = U256::one();
-    let mut b = base;
-
-    while exp > U256::zero() {
-        if exp & U256::one() == U256::one() {
-            result = mul_mod(result, b);
-        }
-        b = mul_mod(b, b);
-        exp = exp >> 1;
+/// Safe version of inv_mod that returns Option
+pub fn try_inv_mod(a: Fr) -> Option<Fr> {
+    if a.is_zero() {
+        return None;
     }
+    Some(Backend::inv(a))
+}
 
-    result
+/// Compute a^exp mod p
+pub fn pow_mod(base: Fr, exp: Fr) -> Fr {
+    Backend::pow(base, exp)
 }
 
 /// Negate a field element
 pub fn neg_mod(a: Fr) -> Fr {
-    if a.is_zero() {
-        U256::zero()
-    } else {
-        MODULUS - a
-    }
+    Backend::neg(a)
 }
 
 /// Square a field element
@@ -139,7 +364,7 @@ pub fn sqr_mod(a: Fr) -> Fr {
 /// Panics if b is zero
 pub fn div_mod(a: Fr, b: Fr) -> Fr {
     assert!(!b.is_zero(), "Division by zero");
-    mul_mod(a, inv_mod(b))
+    mul_mod(a, Backend::inv(b))
 }
 
 /// Safe version of div_mod that returns Option
@@ -147,7 +372,33 @@ pub fn try_div_mod(a: Fr, b: Fr) -> Option<Fr> {
     if b.is_zero() {
         return None;
     }
-    Some(mul_mod(a, inv_mod(b)))
+    Some(mul_mod(a, Backend::inv(b)))
+}
+
+/// Uniformly random field element, by rejection sampling 254-bit values
+#[cfg(any(test, feature = "std"))]
+pub fn random_fr(rng: &mut impl RngCore) -> Fr {
+    loop {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        // p < 2^254, so dropping the top two bits keeps acceptance above 75%
+        bytes[0] &= 0x3f;
+        let candidate = U256::from_big_endian(&bytes);
+        if candidate < MODULUS {
+            return candidate;
+        }
+    }
+}
+
+/// Uniformly random non-zero field element
+#[cfg(any(test, feature = "std"))]
+pub fn random_nonzero_fr(rng: &mut impl RngCore) -> Fr {
+    loop {
+        let candidate = random_fr(rng);
+        if !candidate.is_zero() {
+            return candidate;
+        }
+    }
 }
 
 /// Convert from bytes (big-endian)
@@ -155,6 +406,30 @@ pub fn from_bytes_be(bytes: &[u8; 32]) -> Fr {
     U256::from_big_endian(bytes)
 }
 
+/// The single decoding rule for 32-byte big-endian scalars (public inputs,
+/// proof scalars, VK sizes): exactly 32 bytes, and canonical (`< MODULUS`).
+/// Curve coordinates live in the base field and must not go through this.
+pub trait DecodeFr {
+    fn decode_fr(&self) -> VerifierResult<Fr>;
+}
+
+impl DecodeFr for [u8; 32] {
+    fn decode_fr(&self) -> VerifierResult<Fr> {
+        let value = from_bytes_be(self);
+        if value >= MODULUS {
+            return Err(VerifierError::InvalidFieldElement);
+        }
+        Ok(value)
+    }
+}
+
+impl DecodeFr for [u8] {
+    fn decode_fr(&self) -> VerifierResult<Fr> {
+        let bytes: &[u8; 32] = self.try_into().map_err(|_| VerifierError::InvalidProofFormat)?;
+        bytes.decode_fr()
+    }
+}
+
 /// Convert to bytes (big-endian)
 pub fn to_bytes_be(value: Fr) -> [u8; 32] {
     let mut bytes = [0u8; 32];
@@ -292,6 +567,18 @@ mod tests {
         inv_mod(U256::zero());
     }
 
+    #[test]
+    #[should_panic(expected = "Cannot invert zero")]
+    fn test_inv_mod_modulus_panics() {
+        inv_mod(MODULUS);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot invert zero")]
+    fn test_inv_mod_gcd_modulus_panics() {
+        inv_mod_gcd(MODULUS);
+    }
+
     #[test]
     fn test_div_mod() {
         let a = U256::from(20);
@@ -325,6 +612,49 @@ mod tests {
         assert_eq!(recovered, value);
     }
 
+    #[test]
+    fn test_bytes_layout_is_big_endian() {
+        // The least significant byte is last
+        let bytes = to_bytes_be(U256::one());
+        assert_eq!(bytes[31], 0x01);
+        assert!(bytes[..31].iter().all(|b| *b == 0));
+
+        // 0x0102 is stored as [.., 0x01, 0x02]
+        let bytes = to_bytes_be(U256::from(0x0102));
+        assert_eq!(&bytes[30..], &[0x01, 0x02]);
+
+        // A leading byte lands in the most significant position
+        let mut buf = [0u8; 32];
+        buf[0] = 0x01;
+        assert_eq!(from_bytes_be(&buf), U256::one() << 248);
+
+        let mut buf = [0u8; 32];
+        buf[31] = 0xff;
+        buf[30] = 0x01;
+        assert_eq!(from_bytes_be(&buf), U256::from(0x01ff));
+
+        // The modulus serialises to its well-known big-endian hex form
+        assert_eq!(to_bytes_be(MODULUS)[..4], [0x30, 0x64, 0x4e, 0x72]);
+    }
+
+    #[test]
+    fn test_shift_matches_byte_halves() {
+        let mut buf = [0u8; 32];
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = i as u8 + 1;
+        }
+        let value = from_bytes_be(&buf);
+
+        // Arithmetic >> 128 keeps the first (high) 16 bytes of the big-endian layout
+        let hi = to_bytes_be(value >> 128);
+        assert_eq!(&hi[16..], &buf[..16]);
+        assert!(hi[..16].iter().all(|b| *b == 0));
+
+        // Masking the low 128 bits keeps the last 16 bytes
+        let lo = to_bytes_be(value & ((U256::one() << 128) - 1));
+        assert_eq!(&lo[16..], &buf[16..]);
+    }
+
     #[test]
     fn test_field_properties() {
         let a = U256::from(123);
@@ -356,6 +686,36 @@ mod tests {
         assert_eq!(add_mod(a, neg_a), U256::zero());
     }
 
+    #[test]
+    fn test_random_fr_in_range_and_distinct() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0x1f);
+
+        let mut samples = ink::prelude::vec::Vec::new();
+        for _ in 0..1000 {
+            let value = random_fr(&mut rng);
+            assert!(value < MODULUS);
+            samples.push(value);
+        }
+        samples.sort();
+        samples.dedup();
+        assert_eq!(samples.len(), 1000);
+
+        // The top 16 bits of p are 0x3064, so some samples should land above 2^253
+        assert!(samples.iter().any(|v| v.bits() == 254));
+    }
+
+    #[test]
+    fn test_random_nonzero_fr() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..10 {
+            let value = random_nonzero_fr(&mut rng);
+            assert!(!value.is_zero());
+            assert_eq!(mul_mod(value, inv_mod(value)), U256::one());
+        }
+    }
+
     #[test]
     fn test_modulus_boundary() {
         // Test operations at the modulus boundary
@@ -367,4 +727,139 @@ mod tests {
         // Subtracting from 0 should wrap to p-1
         assert_eq!(sub_mod(U256::zero(), U256::one()), almost_mod);
     }
+
+    #[test]
+    fn test_sub_mod_non_canonical() {
+        let non_canonical = MODULUS + U256::from(5);
+
+        assert_eq!(sub_mod(non_canonical, U256::from(2)), U256::from(3));
+        assert_eq!(sub_mod(U256::from(2), non_canonical), MODULUS - U256::from(3));
+        assert_eq!(sub_mod(non_canonical, non_canonical), U256::zero());
+    }
+
+    #[test]
+    fn test_negative_constants() {
+        assert_eq!(add_mod(MINUS_ONE, U256::one()), U256::zero());
+        assert_eq!(MINUS_ONE, neg_mod(U256::one()));
+        assert_eq!(MINUS_TWO, neg_mod(U256::from(2)));
+        assert_eq!(MINUS_THREE, neg_mod(U256::from(3)));
+
+        // NEG_HALF * 2 = -1
+        assert_eq!(mul_mod(NEG_HALF, U256::from(2)), MINUS_ONE);
+    }
+
+    #[test]
+    fn test_fr_eq_and_cmp_reduce_first() {
+        use core::cmp::Ordering;
+
+        let five = U256::from(5);
+        assert!(fr_eq(five, MODULUS + five));
+        assert!(fr_eq(MODULUS, U256::zero()));
+        assert!(!fr_eq(five, MODULUS + U256::from(6)));
+        assert_eq!(fr_cmp(five, MODULUS + five), Ordering::Equal);
+
+        // Raw U256 order says MODULUS + 1 > 2; as field elements 1 < 2
+        assert_eq!(fr_cmp(MODULUS + U256::one(), U256::from(2)), Ordering::Less);
+        assert_eq!(fr_cmp(MINUS_ONE, U256::MAX), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_is_canonical() {
+        assert!(is_canonical(&U256::zero()));
+        assert!(is_canonical(&MINUS_ONE));
+        assert!(!is_canonical(&MODULUS));
+        assert!(!is_canonical(&(MODULUS + U256::one())));
+        // Differs from MODULUS only below the top limb
+        assert!(!is_canonical(&U256([0, 0, 0, MODULUS.0[3] + 1])));
+        assert!(is_canonical(&U256([u64::MAX, u64::MAX, u64::MAX, MODULUS.0[3] - 1])));
+    }
+
+    #[test]
+    fn test_decode_fr() {
+        assert_eq!(to_bytes_be(U256::from(7)).decode_fr(), Ok(U256::from(7)));
+        assert_eq!(to_bytes_be(MINUS_ONE)[..].decode_fr(), Ok(MINUS_ONE));
+    }
+
+    #[test]
+    fn test_decode_fr_wrong_length() {
+        assert_eq!([0u8; 31][..].decode_fr(), Err(VerifierError::InvalidProofFormat));
+        assert_eq!([0u8; 33][..].decode_fr(), Err(VerifierError::InvalidProofFormat));
+    }
+
+    #[test]
+    fn test_decode_fr_non_canonical() {
+        assert_eq!(to_bytes_be(MODULUS).decode_fr(), Err(VerifierError::InvalidFieldElement));
+        assert_eq!(
+            to_bytes_be(MODULUS + U256::from(5))[..].decode_fr(),
+            Err(VerifierError::InvalidFieldElement)
+        );
+    }
+
+    #[test]
+    fn test_inv_strategies_agree() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0x1640);
+
+        for a in [U256::one(), U256::from(2), MINUS_ONE, NEG_HALF] {
+            assert_eq!(inv_mod_gcd(a), inv_mod(a));
+        }
+        for _ in 0..32 {
+            let a = random_nonzero_fr(&mut rng);
+            let inv = inv_mod_gcd(a);
+            assert_eq!(inv, inv_mod(a));
+            assert_eq!(mul_mod(a, inv), U256::one());
+        }
+    }
+
+    /// Field axioms on random elements, generic over the backend
+    fn check_field_axioms<B: FieldBackend>(seed: u64) {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(seed);
+        let zero = U256::zero();
+        let one = U256::one();
+
+        for _ in 0..16 {
+            let a = random_fr(&mut rng);
+            let b = random_fr(&mut rng);
+            let c = random_fr(&mut rng);
+
+            assert_eq!(B::add(B::add(a, b), c), B::add(a, B::add(b, c)));
+            assert_eq!(B::mul(B::mul(a, b), c), B::mul(a, B::mul(b, c)));
+            assert_eq!(B::add(a, b), B::add(b, a));
+            assert_eq!(B::mul(a, b), B::mul(b, a));
+            assert_eq!(B::mul(a, B::add(b, c)), B::add(B::mul(a, b), B::mul(a, c)));
+            assert_eq!(B::add(a, zero), a);
+            assert_eq!(B::mul(a, one), a);
+            assert_eq!(B::add(a, B::neg(a)), zero);
+            assert_eq!(B::sub(a, b), B::add(a, B::neg(b)));
+            assert_eq!(B::pow(a, U256::from(3)), B::mul(a, B::mul(a, a)));
+            if !a.is_zero() {
+                assert_eq!(B::mul(a, B::inv(a)), one);
+            }
+        }
+        assert_eq!(B::neg(zero), zero);
+        assert_eq!(B::sub(zero, one), MINUS_ONE);
+    }
+
+    #[test]
+    fn test_field_axioms_both_backends() {
+        check_field_axioms::<U256Backend>(0x1645);
+        check_field_axioms::<BigIntBackend>(0x1645);
+    }
+
+    #[test]
+    fn test_backends_agree() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0x1645);
+
+        for _ in 0..16 {
+            let a = random_nonzero_fr(&mut rng);
+            let b = random_fr(&mut rng);
+            assert_eq!(U256Backend::add(a, b), BigIntBackend::add(a, b));
+            assert_eq!(U256Backend::sub(a, b), BigIntBackend::sub(a, b));
+            assert_eq!(U256Backend::mul(a, b), BigIntBackend::mul(a, b));
+            assert_eq!(U256Backend::neg(a), BigIntBackend::neg(a));
+            assert_eq!(U256Backend::inv(a), BigIntBackend::inv(a));
+        }
+    }
 }
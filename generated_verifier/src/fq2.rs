@@ -0,0 +1,116 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Arithmetic in Fq2 = Fq[u] / (u^2 + 1), the field G2 coordinates live in.
+//! Only what the G2 sanity checks need; pairings themselves go through the precompile.
+
+use primitive_types::U256;
+use crate::curve::{add_q, mul_q, sub_q, Q};
+
+/// `c0 + c1 * u`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Fq2 {
+    pub c0: U256,
+    pub c1: U256,
+}
+
+impl Fq2 {
+    pub const fn new(c0: U256, c1: U256) -> Self {
+        Fq2 { c0, c1 }
+    }
+
+    pub fn zero() -> Self {
+        Fq2::default()
+    }
+
+    pub fn one() -> Self {
+        Fq2::new(U256::one(), U256::zero())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    pub fn add(&self, other: &Fq2) -> Fq2 {
+        Fq2::new(add_q(self.c0, other.c0), add_q(self.c1, other.c1))
+    }
+
+    pub fn sub(&self, other: &Fq2) -> Fq2 {
+        Fq2::new(sub_q(self.c0, other.c0), sub_q(self.c1, other.c1))
+    }
+
+    pub fn neg(&self) -> Fq2 {
+        Fq2::zero().sub(self)
+    }
+
+    /// (a0 + a1 u)(b0 + b1 u) = (a0 b0 - a1 b1) + (a0 b1 + a1 b0) u
+    pub fn mul(&self, other: &Fq2) -> Fq2 {
+        Fq2::new(
+            sub_q(mul_q(self.c0, other.c0), mul_q(self.c1, other.c1)),
+            add_q(mul_q(self.c0, other.c1), mul_q(self.c1, other.c0)),
+        )
+    }
+
+    /// (a0 + a1 u)^2 = (a0 + a1)(a0 - a1) + 2 a0 a1 u
+    pub fn square(&self) -> Fq2 {
+        let cross = mul_q(self.c0, self.c1);
+        Fq2::new(
+            mul_q(add_q(self.c0, self.c1), sub_q(self.c0, self.c1)),
+            add_q(cross, cross),
+        )
+    }
+
+    /// 1 / (a0 + a1 u) = (a0 - a1 u) / (a0^2 + a1^2). Panics on zero
+    pub fn inv(&self) -> Fq2 {
+        assert!(!self.is_zero(), "Cannot invert zero");
+        let norm = add_q(mul_q(self.c0, self.c0), mul_q(self.c1, self.c1));
+        let norm_inv = pow_q(norm, Q - U256::from(2));
+        Fq2::new(mul_q(self.c0, norm_inv), sub_q(U256::zero(), mul_q(self.c1, norm_inv)))
+    }
+}
+
+/// base^exp mod q by binary exponentiation
+fn pow_q(base: U256, mut exp: U256) -> U256 {
+    let mut result = U256::one();
+    let mut b = base;
+    while !exp.is_zero() {
+        if exp.bit(0) {
+            result = mul_q(result, b);
+        }
+        b = mul_q(b, b);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> (Fq2, Fq2) {
+        let a = Fq2::new(U256::from(7), Q - U256::from(3));
+        let b = Fq2::new(U256::from(11), U256::from(5));
+        (a, b)
+    }
+
+    #[test]
+    fn test_u_squared_is_minus_one() {
+        let u = Fq2::new(U256::zero(), U256::one());
+        assert_eq!(u.square(), Fq2::one().neg());
+    }
+
+    #[test]
+    fn test_square_matches_mul() {
+        let (a, b) = sample();
+        assert_eq!(a.square(), a.mul(&a));
+        assert_eq!(b.square(), b.mul(&b));
+        assert_eq!(a.mul(&b), b.mul(&a));
+    }
+
+    #[test]
+    fn test_inverse_and_sub() {
+        let (a, b) = sample();
+        assert_eq!(a.mul(&a.inv()), Fq2::one());
+        assert_eq!(a.add(&b).sub(&b), a);
+        assert_eq!(a.add(&a.neg()), Fq2::zero());
+    }
+}
@@ -3,18 +3,16 @@
 use ink::prelude::format;
 use primitive_types::U256;
 use ink::prelude::string::String;
+use crate::curve::{is_on_curve, Q};
+use crate::errors::{VerifierError, VerifierResult};
+use crate::field::{from_bytes_be, DecodeFr};
+use crate::constants::{CONST_PROOF_SIZE_LOG_N, NUMBER_OF_VK_COMMITMENTS, PAIRING_POINTS_SIZE};
 
 // Type alias for field elements
 pub type Fr = U256;
 
 // Field element size
 const FIELD_SIZE: usize = 32;
-// From: uint256 constant N = 32; [cite: 1]
-pub const N: u32 = 32;
-// From: uint256 constant LOG_N = 5; [cite: 1]
-pub const LOG_N: u32 = 5;
-// From: uint256 constant NUMBER_OF_PUBLIC_INPUTS = 4; [cite: 2]
-pub const NUMBER_OF_PUBLIC_INPUTS: u32 = 4;
 
 // From: struct Honk.G1Point [cite: 51]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -24,6 +22,8 @@ pub struct G1Point {
 }
 
 // From: struct Honk.G1ProofPoint [cite: 52]
+// Each coordinate is split by `pack_coord`; serialized and absorbed into the
+// transcript in field order: x_0, x_1, y_0, y_1
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct G1ProofPoint {
     pub x_0: Fr,
@@ -32,12 +32,122 @@ pub struct G1ProofPoint {
     pub y_1: Fr,
 }
 
+/// Proof coordinates are split as `lo | (hi << PROOF_LIMB_BITS)`, as in the
+/// Solidity verifier's `convertProofPoint`
+pub const PROOF_LIMB_BITS: usize = 136;
+
+impl G1ProofPoint {
+    /// Split an affine point into its proof limbs
+    pub fn from_g1(point: &G1Point) -> Self {
+        let (x_0, x_1) = pack_coord(point.x);
+        let (y_0, y_1) = pack_coord(point.y);
+        Self { x_0, x_1, y_0, y_1 }
+    }
+
+    /// Recombine the limbs into an affine point over the base field, see
+    /// `unpack_coord` for what is rejected
+    pub fn to_g1(&self) -> VerifierResult<G1Point> {
+        Ok(G1Point {
+            x: unpack_coord(self.x_0, self.x_1)?,
+            y: unpack_coord(self.y_0, self.y_1)?,
+        })
+    }
+}
+
+/// Split a coordinate into its proof limbs `(lo, hi)`: the low
+/// `PROOF_LIMB_BITS` bits, then the rest
+pub fn pack_coord(coord: Fr) -> (Fr, Fr) {
+    let lo_mask = (U256::one() << PROOF_LIMB_BITS) - U256::one();
+    (coord & lo_mask, coord >> PROOF_LIMB_BITS)
+}
+
+/// `lo | (hi << PROOF_LIMB_BITS)`, the inverse of `pack_coord`. Rejects a limb
+/// wider than its share of the 256 bits with `InvalidLimb`, and a coordinate
+/// `>= q` with `InvalidProofFormat`.
+pub fn unpack_coord(lo: Fr, hi: Fr) -> VerifierResult<Fr> {
+    // `hi` may only use the bits left above the low limb
+    if lo >> PROOF_LIMB_BITS != U256::zero() || hi >> (256 - PROOF_LIMB_BITS) != U256::zero() {
+        return Err(VerifierError::InvalidLimb);
+    }
+    let value = lo | (hi << PROOF_LIMB_BITS);
+    if value >= Q {
+        return Err(VerifierError::InvalidProofFormat);
+    }
+    Ok(value)
+}
+
+/// Pairing-point public inputs carry each coordinate as four limbs of this many
+/// bits, low limb first, as in the Solidity verifier's `convertPairingPointsToG1`
+pub const PAIRING_POINT_LIMB_BITS: usize = 68;
+
+/// Decode the `PAIRING_POINTS_SIZE` limbs of a recursive proof's pairing-point
+/// accumulator into its `(lhs, rhs)` points: lhs.x, lhs.y, rhs.x, rhs.y, four
+/// limbs each. Rejects oversized limbs, coordinates `>= q` and off-curve points,
+/// reporting the public-input index of the offending limb (the point's first
+/// limb when off-curve), where `limbs[0]` is public input `first_index`.
+pub fn pairing_points_to_g1(
+    limbs: &[Fr; PAIRING_POINTS_SIZE],
+    first_index: usize,
+) -> VerifierResult<(G1Point, G1Point)> {
+    let invalid = |limb: usize| VerifierError::InvalidPublicInputFormat { index: (first_index + limb) as u32 };
+    let coordinate = |index: usize| -> VerifierResult<Fr> {
+        let mut value = U256::zero();
+        for (k, limb) in limbs[4 * index..4 * index + 4].iter().enumerate() {
+            let shift = k * PAIRING_POINT_LIMB_BITS;
+            // The top limb only has the bits left below 256
+            let width = PAIRING_POINT_LIMB_BITS.min(256 - shift);
+            if *limb >> width != U256::zero() {
+                return Err(invalid(4 * index + k));
+            }
+            value |= *limb << shift;
+        }
+        if value >= Q {
+            return Err(invalid(4 * index));
+        }
+        Ok(value)
+    };
+
+    let lhs = G1Point { x: coordinate(0)?, y: coordinate(1)? };
+    if !is_on_curve(&lhs) {
+        return Err(invalid(0));
+    }
+    let rhs = G1Point { x: coordinate(2)?, y: coordinate(3)? };
+    if !is_on_curve(&rhs) {
+        return Err(invalid(8));
+    }
+    Ok((lhs, rhs))
+}
+
+/// G2 point over Fq2, each coordinate stored as `c0 + c1 * i`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G2Point {
+    pub x_c0: Fr,
+    pub x_c1: Fr,
+    pub y_c0: Fr,
+    pub y_c1: Fr,
+}
+
+impl G2Point {
+    /// EIP-197 encoding used by the pairing precompile: imaginary part first
+    pub fn to_bytes(&self) -> [u8; 128] {
+        let mut bytes = [0u8; 128];
+        self.x_c1.to_big_endian(&mut bytes[0..32]);
+        self.x_c0.to_big_endian(&mut bytes[32..64]);
+        self.y_c1.to_big_endian(&mut bytes[64..96]);
+        self.y_c0.to_big_endian(&mut bytes[96..128]);
+        bytes
+    }
+}
+
 // From: struct Honk.VerificationKey [cite: 53-63]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct VerificationKey {
     pub circuit_size: Fr,
     pub log_circuit_size: Fr,
     pub public_inputs_size: Fr,
+    /// The last `PAIRING_POINTS_SIZE` public inputs are a recursive proof's
+    /// pairing-point accumulator, folded into the final pairing check
+    pub has_pairing_points: bool,
     pub ql: G1Point,
     pub qr: G1Point,
     pub qo: G1Point,
@@ -67,6 +177,241 @@ pub struct VerificationKey {
     pub lagrange_last: G1Point,
 }
 
+impl VerificationKey {
+    /// All G1 commitments with their field names, in struct field order
+    pub fn named_commitments(&self) -> [(&'static str, G1Point); NUMBER_OF_VK_COMMITMENTS] {
+        [
+            ("ql", self.ql),
+            ("qr", self.qr),
+            ("qo", self.qo),
+            ("q4", self.q4),
+            ("qm", self.qm),
+            ("qc", self.qc),
+            ("q_arith", self.q_arith),
+            ("q_delta_range", self.q_delta_range),
+            ("q_elliptic", self.q_elliptic),
+            ("q_aux", self.q_aux),
+            ("q_lookup", self.q_lookup),
+            ("q_poseidon2_external", self.q_poseidon2_external),
+            ("q_poseidon2_internal", self.q_poseidon2_internal),
+            ("s1", self.s1),
+            ("s2", self.s2),
+            ("s3", self.s3),
+            ("s4", self.s4),
+            ("t1", self.t1),
+            ("t2", self.t2),
+            ("t3", self.t3),
+            ("t4", self.t4),
+            ("id1", self.id1),
+            ("id2", self.id2),
+            ("id3", self.id3),
+            ("id4", self.id4),
+            ("lagrange_first", self.lagrange_first),
+            ("lagrange_last", self.lagrange_last),
+        ]
+    }
+
+    /// All G1 commitments, in struct field order
+    pub fn commitments(&self) -> [G1Point; NUMBER_OF_VK_COMMITMENTS] {
+        self.named_commitments().map(|(_, point)| point)
+    }
+
+    /// Number of sumcheck rounds. The proof arrays only hold
+    /// `CONST_PROOF_SIZE_LOG_N` rounds, so a larger claim is rejected instead of
+    /// running a truncated sumcheck.
+    pub fn log_n(&self) -> VerifierResult<usize> {
+        if self.log_circuit_size > U256::from(CONST_PROOF_SIZE_LOG_N) {
+            return Err(VerifierError::InvalidVerificationKey);
+        }
+        Ok(self.log_circuit_size.as_usize())
+    }
+
+    /// Fails unless exactly `public_inputs_size` inputs were supplied. Compared
+    /// as `U256`, so an oversized VK field can't wrap around to match.
+    pub fn check_public_inputs_len(&self, got: usize) -> VerifierResult<()> {
+        if U256::from(got) != self.public_inputs_size {
+            return Err(VerifierError::InvalidPublicInputsLength {
+                expected: u32::try_from(self.public_inputs_size).unwrap_or(u32::MAX),
+                got: got as u32,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Cheap structural checks on a VK, no proof needed:
+/// - `circuit_size` is `2^log_circuit_size` and fits the fixed proof size
+/// - every commitment is on the curve (or the point at infinity)
+pub fn self_test(vk: &VerificationKey) -> VerifierResult<()> {
+    if vk.circuit_size != U256::one() << vk.log_n()? {
+        return Err(VerifierError::InvalidVerificationKey);
+    }
+    if !vk.commitments().iter().all(is_on_curve) {
+        return Err(VerifierError::InvalidVerificationKey);
+    }
+    Ok(())
+}
+
+/// Scalars plus `x`/`y` for every commitment, as laid out by `parse_vk_structured`
+pub const VK_FIELD_COUNT: usize = 3 + 2 * NUMBER_OF_VK_COMMITMENTS;
+
+impl VerificationKey {
+    /// Build a VK from already-decoded fields in `parse_vk_structured` order:
+    /// circuit size, log size, public input count, then each commitment's `x`, `y`
+    pub fn from_field_slice(fields: &[Fr]) -> VerifierResult<VerificationKey> {
+        if fields.len() != VK_FIELD_COUNT {
+            return Err(VerifierError::InvalidVerificationKey);
+        }
+
+        let mut index = 0;
+
+        let read_fr = |index: &mut usize| -> Fr {
+            *index += 1;
+            fields[*index - 1]
+        };
+
+        let read_g1 = |index: &mut usize| -> G1Point {
+            G1Point {
+                x: read_fr(index),
+                y: read_fr(index),
+            }
+        };
+
+        Ok(VerificationKey {
+            circuit_size: read_fr(&mut index),
+            log_circuit_size: read_fr(&mut index),
+            public_inputs_size: read_fr(&mut index),
+            has_pairing_points: false,
+            ql: read_g1(&mut index),
+            qr: read_g1(&mut index),
+            qo: read_g1(&mut index),
+            q4: read_g1(&mut index),
+            qm: read_g1(&mut index),
+            qc: read_g1(&mut index),
+            q_arith: read_g1(&mut index),
+            q_delta_range: read_g1(&mut index),
+            q_elliptic: read_g1(&mut index),
+            q_aux: read_g1(&mut index),
+            q_lookup: read_g1(&mut index),
+            q_poseidon2_external: read_g1(&mut index),
+            q_poseidon2_internal: read_g1(&mut index),
+            s1: read_g1(&mut index),
+            s2: read_g1(&mut index),
+            s3: read_g1(&mut index),
+            s4: read_g1(&mut index),
+            t1: read_g1(&mut index),
+            t2: read_g1(&mut index),
+            t3: read_g1(&mut index),
+            t4: read_g1(&mut index),
+            id1: read_g1(&mut index),
+            id2: read_g1(&mut index),
+            id3: read_g1(&mut index),
+            id4: read_g1(&mut index),
+            lagrange_first: read_g1(&mut index),
+            lagrange_last: read_g1(&mut index),
+        })
+    }
+}
+
+/// Fields ahead of the commitments in bb's VK: circuit size, public input
+/// count, public inputs offset, the pairing-point accumulator flag, then the
+/// accumulator's public-input indices
+pub const BB_VK_HEADER_FIELDS: usize = 4 + PAIRING_POINTS_SIZE;
+
+/// Fields in bb's VK: the header, then four limbs per commitment
+pub const BB_VK_FIELD_COUNT: usize = BB_VK_HEADER_FIELDS + 4 * NUMBER_OF_VK_COMMITMENTS;
+
+impl VerificationKey {
+    /// Read a VK in the field layout `bb write_vk` produces, which is what
+    /// `ink-generator` embeds and `set_vk` takes:
+    ///
+    /// ```text
+    /// [ circuit_size | public_inputs_size | pub_inputs_offset | pairing-point flag
+    ///   | pairing-point input indices (PAIRING_POINTS_SIZE)
+    ///   | commitments in Solidity order, each x_0, x_1, y_0, y_1 (see `pack_coord`) ]
+    /// ```
+    ///
+    /// `log_circuit_size` is not stored; `circuit_size` must be a power of two
+    /// and the log is taken from it. The offset must be 1, the only one the
+    /// transcript and permutation argument are built for. The flag must be 0 or
+    /// 1; when set, the indices must name the trailing `PAIRING_POINTS_SIZE`
+    /// public inputs in order, where the verifier reads the accumulator. When
+    /// clear, bb leaves the indices unset and they are ignored. Coordinates are
+    /// range checked but not checked to be on the curve; that is `self_test`.
+    pub fn from_bb_fields(fields: &[[u8; 32]]) -> VerifierResult<VerificationKey> {
+        if fields.len() != BB_VK_FIELD_COUNT {
+            return Err(VerifierError::InvalidVerificationKey);
+        }
+        let invalid = |_| VerifierError::InvalidVerificationKey;
+
+        let circuit_size = fields[0].decode_fr().map_err(invalid)?;
+        let public_inputs_size = fields[1].decode_fr().map_err(invalid)?;
+        if circuit_size.is_zero() || !(circuit_size & (circuit_size - U256::one())).is_zero() {
+            return Err(VerifierError::InvalidVerificationKey);
+        }
+        if from_bytes_be(&fields[2]) != U256::one() {
+            return Err(VerifierError::InvalidVerificationKey);
+        }
+        let has_pairing_points = match from_bytes_be(&fields[3]) {
+            flag if flag.is_zero() => false,
+            flag if flag == U256::one() => true,
+            _ => return Err(VerifierError::InvalidVerificationKey),
+        };
+        if has_pairing_points {
+            // Trailing inputs, so public_inputs_size must have room for them
+            let first = public_inputs_size
+                .checked_sub(U256::from(PAIRING_POINTS_SIZE))
+                .ok_or(VerifierError::InvalidVerificationKey)?;
+            let indices = &fields[4..BB_VK_HEADER_FIELDS];
+            if indices.iter().enumerate().any(|(k, index)| from_bytes_be(index) != first + k) {
+                return Err(VerifierError::InvalidVerificationKey);
+            }
+        }
+
+        let g1 = |index: usize| -> VerifierResult<G1Point> {
+            let limbs = &fields[BB_VK_HEADER_FIELDS + 4 * index..BB_VK_HEADER_FIELDS + 4 * index + 4];
+            Ok(G1Point {
+                x: unpack_coord(from_bytes_be(&limbs[0]), from_bytes_be(&limbs[1])).map_err(invalid)?,
+                y: unpack_coord(from_bytes_be(&limbs[2]), from_bytes_be(&limbs[3])).map_err(invalid)?,
+            })
+        };
+
+        Ok(VerificationKey {
+            circuit_size,
+            log_circuit_size: U256::from(circuit_size.trailing_zeros()),
+            public_inputs_size,
+            has_pairing_points,
+            qm: g1(0)?,
+            qc: g1(1)?,
+            ql: g1(2)?,
+            qr: g1(3)?,
+            qo: g1(4)?,
+            q4: g1(5)?,
+            q_lookup: g1(6)?,
+            q_arith: g1(7)?,
+            q_delta_range: g1(8)?,
+            q_elliptic: g1(9)?,
+            q_aux: g1(10)?,
+            q_poseidon2_external: g1(11)?,
+            q_poseidon2_internal: g1(12)?,
+            s1: g1(13)?,
+            s2: g1(14)?,
+            s3: g1(15)?,
+            s4: g1(16)?,
+            id1: g1(17)?,
+            id2: g1(18)?,
+            id3: g1(19)?,
+            id4: g1(20)?,
+            t1: g1(21)?,
+            t2: g1(22)?,
+            t3: g1(23)?,
+            t4: g1(24)?,
+            lagrange_first: g1(25)?,
+            lagrange_last: g1(26)?,
+        })
+    }
+}
+
 /// Parse VK bytes into structured VerificationKey
 pub fn parse_vk_structured(vk_bytes: &[u8]) -> Result<VerificationKey, String> {
     if vk_bytes.len() != 128 * FIELD_SIZE {
@@ -92,10 +437,20 @@ pub fn parse_vk_structured(vk_bytes: &[u8]) -> Result<VerificationKey, String> {
         }
     };
 
+    // Sizes are scalars; the commitment coordinates are base-field elements
+    let read_scalar = |offset: &mut usize| -> Result<Fr, String> {
+        let value = vk_bytes[*offset..*offset + 32]
+            .decode_fr()
+            .map_err(|e| format!("Invalid VK scalar at byte {}: {:?}", *offset, e))?;
+        *offset += 32;
+        Ok(value)
+    };
+
     Ok(VerificationKey {
-        circuit_size: read_fr(&mut offset),
-        log_circuit_size: read_fr(&mut offset),
-        public_inputs_size: read_fr(&mut offset),
+        circuit_size: read_scalar(&mut offset)?,
+        log_circuit_size: read_scalar(&mut offset)?,
+        public_inputs_size: read_scalar(&mut offset)?,
+        has_pairing_points: false,
         ql: read_g1(&mut offset),
         qr: read_g1(&mut offset),
         qo: read_g1(&mut offset),
@@ -125,3 +480,229 @@ pub fn parse_vk_structured(vk_bytes: &[u8]) -> Result<VerificationKey, String> {
         lagrange_last: read_g1(&mut offset),
     })
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const G1_GEN: G1Point = G1Point {
+        x: U256([1, 0, 0, 0]),
+        y: U256([2, 0, 0, 0]),
+    };
+
+    fn valid_vk() -> VerificationKey {
+        VerificationKey {
+            circuit_size: U256::from(32),
+            log_circuit_size: U256::from(5),
+            public_inputs_size: U256::from(1),
+            has_pairing_points: false,
+            ql: G1_GEN,
+            qr: G1_GEN,
+            qo: G1_GEN,
+            q4: G1_GEN,
+            qm: G1_GEN,
+            qc: G1_GEN,
+            q_arith: G1_GEN,
+            q_delta_range: G1_GEN,
+            q_elliptic: G1_GEN,
+            q_aux: G1_GEN,
+            q_lookup: G1_GEN,
+            q_poseidon2_external: G1_GEN,
+            q_poseidon2_internal: G1_GEN,
+            s1: G1_GEN,
+            s2: G1_GEN,
+            s3: G1_GEN,
+            s4: G1_GEN,
+            t1: G1_GEN,
+            t2: G1_GEN,
+            t3: G1_GEN,
+            t4: G1_GEN,
+            id1: G1_GEN,
+            id2: G1_GEN,
+            id3: G1_GEN,
+            id4: G1_GEN,
+            lagrange_first: G1_GEN,
+            // Unused selectors may legitimately commit to the zero polynomial
+            lagrange_last: G1Point::default(),
+        }
+    }
+
+    #[test]
+    fn test_proof_point_round_trip() {
+        // -G has a full-width y, so both limbs are populated
+        let point = crate::curve::negate(&G1_GEN);
+        let limbs = G1ProofPoint::from_g1(&point);
+
+        assert_ne!(limbs.y_1, U256::zero());
+        assert_eq!(limbs.y_0 >> PROOF_LIMB_BITS, U256::zero());
+        assert_eq!(limbs.to_g1(), Ok(point));
+    }
+
+    #[test]
+    fn test_proof_point_over_wide_limb() {
+        let mut limbs = G1ProofPoint::from_g1(&G1_GEN);
+        limbs.x_0 = U256::one() << PROOF_LIMB_BITS;
+        assert_eq!(limbs.to_g1(), Err(VerifierError::InvalidLimb));
+
+        // A high limb that would shift past 256 bits
+        let mut limbs = G1ProofPoint::from_g1(&G1_GEN);
+        limbs.y_1 = U256::one() << (256 - PROOF_LIMB_BITS);
+        assert_eq!(limbs.to_g1(), Err(VerifierError::InvalidLimb));
+    }
+
+    #[test]
+    fn test_pack_coord_round_trip() {
+        for coord in [U256::zero(), U256::one() << PROOF_LIMB_BITS, Q - U256::one()] {
+            let (lo, hi) = pack_coord(coord);
+            assert_eq!(lo >> PROOF_LIMB_BITS, U256::zero());
+            assert_eq!(unpack_coord(lo, hi), Ok(coord));
+        }
+
+        // Top bit of the high limb set: shifts past 256 bits
+        let (lo, _) = pack_coord(U256::from(7));
+        assert_eq!(unpack_coord(lo, U256::MAX), Err(VerifierError::InvalidLimb));
+    }
+
+    #[test]
+    fn test_proof_point_coordinate_out_of_field() {
+        let limbs = G1ProofPoint::from_g1(&G1Point { x: Q, y: U256::from(2) });
+        assert_eq!(limbs.to_g1(), Err(VerifierError::InvalidProofFormat));
+    }
+
+    #[test]
+    fn test_from_field_slice_matches_byte_parse() {
+        let fields: Vec<Fr> = (1..=VK_FIELD_COUNT as u64).map(U256::from).collect();
+
+        // The byte format is padded to 128 fields; the tail is ignored
+        let mut bytes = [0u8; 128 * FIELD_SIZE];
+        for (i, field) in fields.iter().enumerate() {
+            field.to_big_endian(&mut bytes[i * FIELD_SIZE..(i + 1) * FIELD_SIZE]);
+        }
+
+        let vk = VerificationKey::from_field_slice(&fields).unwrap();
+        assert_eq!(Ok(vk), parse_vk_structured(&bytes));
+        assert_eq!(vk.lagrange_last, G1Point { x: U256::from(56), y: U256::from(57) });
+    }
+
+    #[test]
+    fn test_from_field_slice_wrong_length() {
+        let fields = [U256::one(); VK_FIELD_COUNT - 1];
+        assert_eq!(
+            VerificationKey::from_field_slice(&fields),
+            Err(VerifierError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn test_self_test_valid_vk() {
+        assert_eq!(self_test(&valid_vk()), Ok(()));
+    }
+
+    #[test]
+    fn test_self_test_off_curve_commitment() {
+        let mut vk = valid_vk();
+        vk.qm = G1Point { x: U256::one(), y: U256::from(3) };
+        assert_eq!(self_test(&vk), Err(VerifierError::InvalidVerificationKey));
+    }
+
+    #[test]
+    fn test_self_test_inconsistent_sizes() {
+        let mut vk = valid_vk();
+        vk.circuit_size = U256::from(33);
+        assert_eq!(self_test(&vk), Err(VerifierError::InvalidVerificationKey));
+
+        let mut vk = valid_vk();
+        vk.log_circuit_size = U256::from(CONST_PROOF_SIZE_LOG_N + 1);
+        vk.circuit_size = U256::one() << (CONST_PROOF_SIZE_LOG_N + 1);
+        assert_eq!(self_test(&vk), Err(VerifierError::InvalidVerificationKey));
+    }
+
+    #[test]
+    fn test_public_inputs_len_compared_in_full() {
+        // Truncated to 32 bits this would claim a single input
+        let vk = VerificationKey { public_inputs_size: (U256::one() << 32) + 1, ..VerificationKey::default() };
+        assert_eq!(
+            vk.check_public_inputs_len(1),
+            Err(VerifierError::InvalidPublicInputsLength { expected: u32::MAX, got: 1 })
+        );
+
+        let vk = VerificationKey { public_inputs_size: U256::from(3), ..vk };
+        assert_eq!(vk.check_public_inputs_len(3), Ok(()));
+    }
+
+    fn fixture_vk_fields() -> Vec<[u8; 32]> {
+        include_bytes!("../tests/fixtures/vk")
+            .chunks_exact(FIELD_SIZE)
+            .map(|field| field.try_into().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_fixture_vk_in_bb_layout() {
+        let vk = VerificationKey::from_bb_fields(&fixture_vk_fields()).unwrap();
+        assert_eq!(self_test(&vk), Ok(()));
+        assert_eq!(vk.log_n(), Ok(5));
+        assert_eq!(vk.public_inputs_size, U256::one());
+        // First commitment: the four limbs right after the header
+        assert_eq!(vk.qm.x >> PROOF_LIMB_BITS, U256::from_str_radix("218b93f3fab303e84c3e8b2090d481", 16).unwrap());
+    }
+
+    #[test]
+    fn test_bb_layout_rejects_malformed_header() {
+        let fields = fixture_vk_fields();
+        assert!(VerificationKey::from_bb_fields(&fields[1..]).is_err());
+
+        for (index, value) in [(0, 33), (0, 0), (2, 0)] {
+            let mut fields = fields.clone();
+            fields[index] = crate::field::to_bytes_be(U256::from(value));
+            assert_eq!(
+                VerificationKey::from_bb_fields(&fields),
+                Err(VerifierError::InvalidVerificationKey)
+            );
+        }
+
+        // Off the curve: structurally fine, caught by `self_test`
+        let mut fields = fields;
+        fields[BB_VK_HEADER_FIELDS][31] ^= 1;
+        let vk = VerificationKey::from_bb_fields(&fields).unwrap();
+        assert_eq!(self_test(&vk), Err(VerifierError::InvalidVerificationKey));
+    }
+
+    /// Four 68-bit limbs, low first
+    fn to_limbs(value: U256) -> [U256; 4] {
+        let mask = (U256::one() << PAIRING_POINT_LIMB_BITS) - U256::one();
+        core::array::from_fn(|k| (value >> (k * PAIRING_POINT_LIMB_BITS)) & mask)
+    }
+
+    #[test]
+    fn test_pairing_points_round_trip() {
+        // lhs = G = (1, 2), rhs = -G
+        let minus_two = Q - U256::from(2);
+        let mut limbs = [U256::zero(); PAIRING_POINTS_SIZE];
+        for (i, value) in [U256::one(), U256::from(2), U256::one(), minus_two].into_iter().enumerate() {
+            limbs[4 * i..4 * i + 4].copy_from_slice(&to_limbs(value));
+        }
+        assert_ne!(limbs[15], U256::zero());
+
+        let (lhs, rhs) = pairing_points_to_g1(&limbs, 5).unwrap();
+        assert_eq!(lhs, G1Point { x: U256::one(), y: U256::from(2) });
+        assert_eq!(rhs, G1Point { x: U256::one(), y: minus_two });
+
+        // A limb wider than 68 bits
+        let mut wide = limbs;
+        wide[1] = U256::one() << PAIRING_POINT_LIMB_BITS;
+        assert_eq!(
+            pairing_points_to_g1(&wide, 5),
+            Err(VerifierError::InvalidPublicInputFormat { index: 6 })
+        );
+
+        // rhs = (1, 3) is off the curve
+        let mut off_curve = limbs;
+        off_curve[12..].copy_from_slice(&to_limbs(U256::from(3)));
+        assert_eq!(
+            pairing_points_to_g1(&off_curve, 5),
+            Err(VerifierError::InvalidPublicInputFormat { index: 13 })
+        );
+    }
+}
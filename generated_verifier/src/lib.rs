@@ -1,813 +1,606 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-mod errors;
-mod field;
-mod honk_structs;
-mod relations;
-mod transcript;
+/// `tracing::debug!` with the `tracing` feature, nothing otherwise
+macro_rules! debug_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+pub mod audit;
+pub mod constants;
+pub mod curve;
+pub mod errors;
+pub mod field;
+pub mod fq2;
+pub mod honk_structs;
+pub mod proof_reader;
+pub mod relations;
+pub mod sumcheck;
+pub mod transcript;
+#[cfg(test)]
+mod testutil;
+
+pub use verifier::VerifySession;
 
 #[ink::contract]
 mod verifier {
-    use crate::errors::{VerifierError, VerifierResult};
-    use crate::field::{add_mod, from_bytes_be, mul_mod, sub_mod, div_mod, to_bytes_be, Fr, MODULUS};
+    use crate::constants::{
+        BATCHED_RELATION_PARTIAL_LENGTH, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ENTITIES, NUMBER_UNSHIFTED,
+        PAIRING_POINTS_SIZE,
+    };
+    use crate::curve::{is_infinity, is_on_curve, negate, G2_GEN, G2_X};
+    use crate::errors::{VerifierError, VerifierResult, VerifyStage};
+    use crate::field::{DecodeFr, add_mod, from_bytes_be, mul_mod, neg_mod, sqr_mod, sub_mod, to_bytes_be, try_inv_mod, Fr};
     use primitive_types::U256;
-    use crate::honk_structs::{G1Point, G1ProofPoint, VerificationKey};
-    use crate::transcript::{Proof, Transcript};
+    use crate::honk_structs::{pairing_points_to_g1, self_test, G1Point, G1ProofPoint, VerificationKey};
+    use crate::proof_reader::{ProofReader, PROOF_SIZE};
+    use crate::transcript::{
+        check_public_input_lengths, recursion_separator, Proof, ProofOpening, Transcript,
+    };
     use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::hash::{HashOutput, Keccak256};
     use ink::env::DefaultEnvironment;
+    use ink::prelude::borrow::Cow;
     use ink::prelude::vec::Vec;
     use ink::primitives::H160;
+    use ink::storage::Lazy;
 
     // --- PRECOMPILE ADDRESSES ---
     // These are the EVM-compatible precompile addresses from pallet-revive
     const SHA256_ADDR: H160 = H160([
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x02,
     ]);
-    const BN128_ADD_ADDR: H160 = H160([
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x06,
-    ]);
-    const BN128_MUL_ADDR: H160 = H160([
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x07,
-    ]);
-    const BN128_PAIRING_ADDR: H160 = H160([
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x08,
-    ]);
-    // --- ULTRAHONK PROOF CONSTANTS ---
-    const CONST_PROOF_SIZE_LOG_N: usize = 28;
-    const BATCHED_RELATION_PARTIAL_LENGTH: usize = 8;
-    const NUMBER_OF_ENTITIES: usize = 40;
-    const NUMBER_OF_ALPHAS: usize = 25;
-
     // --- INJECTED HONK VERIFICATION KEY ---
     const VK_LEN: usize = 128;
-    const VK: [[u8; 32]; VK_LEN] = [
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x20,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x01,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x01,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0xc0, 0x25, 0x0c, 0x6f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x09, 0x61, 0x1f, 0x59,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x15, 0x6e, 0x03, 0x55,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x90, 0x0a, 0x3f, 0x7d,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0xa9, 0xb5, 0x94, 0x48,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0xd1, 0xdd, 0xe7, 0x84,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x6f, 0xd6, 0x34, 0x35,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x63, 0xa7, 0x0e, 0xcc,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0xde, 0x0f, 0x83, 0xb4,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x04, 0xf0, 0x35, 0xd6,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0xa2, 0xe6, 0x3c, 0x09,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x7b, 0x48, 0x18, 0x78,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x9d, 0x34, 0x45, 0x2b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x98, 0x82, 0xa0, 0x17,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x8e, 0xd2, 0xd1, 0x6e,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0xfc, 0x8b, 0x95, 0xf5,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x6f, 0xdc, 0xe4, 0x58, 0xb0, 0x8b, 0x2e, 0xda, 0xf7, 0x37, 0x52, 0x0a, 0x25,
-            0xce, 0x0b, 0xa9, 0x96,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x21, 0x8b, 0x93, 0xf3, 0xfa, 0xb3, 0x03, 0xe8, 0x4c, 0x3e, 0x8b,
-            0x20, 0x90, 0xd4, 0x81,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x94, 0xb8, 0x93, 0xc3, 0x61, 0x18, 0x5f, 0xc1, 0x26, 0x8a, 0x00, 0xee, 0xe4,
-            0x2d, 0x86, 0x52, 0xcd,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x07, 0x92, 0xc7, 0x4b, 0xa6, 0x60, 0x04, 0xeb, 0xf2, 0x00, 0x2e,
-            0x06, 0x15, 0xbc, 0xfd,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x5f, 0x31, 0xbc, 0x77, 0x68, 0xbf, 0x56, 0x85, 0xd4, 0x4b, 0xd9, 0xb8, 0x54,
-            0xbe, 0x2b, 0x70, 0xa4,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x24, 0x91, 0x36, 0xba, 0xa4, 0x36, 0xc0, 0x1e, 0xd3, 0x49, 0xfa,
-            0xaf, 0x73, 0x80, 0x55,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xbb, 0xf7, 0xbc, 0xa4, 0xda, 0xaa, 0x5d, 0x71, 0x6f, 0xd6, 0xc8, 0x0d, 0x8b,
-            0x9a, 0x9f, 0xd2, 0x2a,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2c, 0x7f, 0x6d, 0xfb, 0x6e, 0x2b, 0x2e, 0xc3, 0x71, 0xb4, 0xe0,
-            0xdd, 0x99, 0xd5, 0xc5,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x28, 0xed, 0xd1, 0xa7, 0xe4, 0x6c, 0x84, 0x0d, 0x9c, 0x94, 0x3f, 0xdf, 0x45,
-            0x52, 0x1c, 0x64, 0xce,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x04, 0x3d, 0x06, 0x3b, 0x13, 0x0a, 0xdf, 0xb3, 0x73, 0x42, 0xaf,
-            0x45, 0xd0, 0x15, 0x5a,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x93, 0x30, 0x95, 0x2a, 0xe7, 0x4c, 0x57, 0x3d, 0x16, 0x86, 0xd9, 0xcb, 0x4a,
-            0x00, 0x73, 0x38, 0x54,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x26, 0x15, 0x22, 0xc4, 0x08, 0x93, 0x30, 0x64, 0x6a, 0xff, 0x96,
-            0x73, 0x61, 0x94, 0x94,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xfc, 0x80, 0xea, 0x49, 0x6f, 0x69, 0x5e, 0x68, 0x1e, 0x5b, 0x2e, 0x81, 0xe2,
-            0x89, 0x96, 0xa8, 0x56,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x18, 0xbc, 0x17, 0xb1, 0x96, 0x9a, 0xd2, 0x45, 0xf8, 0xca, 0xa3,
-            0xf4, 0x67, 0x17, 0x6d,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xe3, 0x9e, 0xfa, 0x72, 0x16, 0x44, 0xb3, 0x4f, 0xe5, 0xe4, 0x73, 0xe5, 0x69,
-            0x13, 0xe1, 0xc8, 0x10,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x21, 0xf7, 0x4c, 0x19, 0x2e, 0xb4, 0x15, 0x02, 0x71, 0xb8, 0x54,
-            0xeb, 0xa3, 0x99, 0x4f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x50, 0x80, 0xcd, 0x58, 0x34, 0xb1, 0x58, 0x42, 0xe0, 0xab, 0xec, 0xb6, 0x82,
-            0xb8, 0x1a, 0xca, 0x71,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x15, 0x27, 0x9f, 0x91, 0x39, 0xfe, 0x92, 0x4c, 0xd7, 0xac, 0x26,
-            0x82, 0x34, 0x73, 0x1b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x85, 0x15, 0x24, 0x54, 0x05, 0xe6, 0xef, 0xfd, 0x84, 0x81, 0x8a, 0x49, 0xe5,
-            0x45, 0x83, 0xcd, 0x9f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x27, 0xe3, 0x77, 0xf2, 0x75, 0x0e, 0x22, 0x16, 0x06, 0x60, 0x86,
-            0xd9, 0x2b, 0xcd, 0x74,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xed, 0xa1, 0x13, 0x99, 0xdc, 0x21, 0xe2, 0x3d, 0xf4, 0xbe, 0xe4, 0xb5, 0xcd,
-            0x0b, 0xec, 0x65, 0x9f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x1e, 0xec, 0x24, 0x71, 0x54, 0xce, 0xd5, 0xc2, 0x9b, 0x08, 0x36,
-            0x52, 0x8d, 0x7c, 0x19,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x0b, 0xdb, 0x0c, 0x0a, 0xc4, 0xb0, 0x54, 0xa9, 0x05, 0xb0, 0x3b, 0x9d, 0x53,
-            0xbe, 0xbe, 0xf4, 0x04,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x10, 0x7c, 0xc3, 0x82, 0xfd, 0xee, 0x2f, 0x65, 0x30, 0xd3, 0x9b,
-            0x07, 0x2a, 0x2b, 0xc5,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x72, 0xbd, 0x9f, 0x54, 0xe6, 0xe9, 0xb5, 0x71, 0x29, 0xce, 0x99, 0x5b, 0x19,
-            0xce, 0xc0, 0xc9, 0x0f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x24, 0x53, 0xe0, 0x56, 0xdc, 0x17, 0x9b, 0xdc, 0x91, 0x64, 0xde,
-            0x8e, 0x36, 0x54, 0xed,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xa0, 0x43, 0x13, 0x9c, 0x10, 0xe1, 0x84, 0x0d, 0x9a, 0x1a, 0x7b, 0xba, 0x09,
-            0x89, 0x61, 0x8a, 0x5a,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x15, 0xbc, 0x46, 0x80, 0xdb, 0x7e, 0xb8, 0x10, 0x0d, 0x97, 0xa8,
-            0xcf, 0xae, 0xab, 0xcd,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xcb, 0x39, 0x5b, 0x82, 0x7e, 0x90, 0x79, 0xe9, 0x99, 0xb6, 0xc1, 0x28, 0xd9,
-            0xb0, 0x83, 0xc3, 0x95,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x17, 0x97, 0xe3, 0xe7, 0xee, 0x9e, 0x4f, 0x42, 0xb4, 0x2b, 0xd3,
-            0x75, 0xf1, 0x3f, 0x2c,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xef, 0xac, 0xf4, 0x59, 0x41, 0xc3, 0xef, 0xe9, 0x3c, 0x35, 0x68, 0xbd, 0xe1,
-            0xe0, 0x8e, 0x19, 0x19,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x10, 0x1a, 0x60, 0xef, 0xaa, 0xb1, 0xc8, 0x56, 0x4a, 0xdd, 0x45,
-            0xd4, 0x1b, 0x91, 0x47,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x38, 0x96, 0xea, 0x79, 0x3e, 0x6b, 0x3f, 0x6a, 0x14, 0x21, 0x8d, 0x47, 0x65,
-            0x34, 0x10, 0x96, 0x10,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x0e, 0x84, 0x09, 0x0a, 0xdd, 0x56, 0xf2, 0x50, 0x0a, 0xb5, 0x18,
-            0xc6, 0x55, 0xca, 0xe6,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x65, 0xdf, 0x44, 0x6f, 0xdd, 0xdb, 0xa9, 0x72, 0xf3, 0xc4, 0x41, 0x4a, 0xd3,
-            0xc9, 0x01, 0xf4, 0xf9,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2b, 0x78, 0xa5, 0x84, 0xbd, 0x6a, 0xe8, 0x8c, 0xf4, 0xec, 0x7c,
-            0x65, 0xc9, 0x0e, 0x0b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x94, 0xe6, 0x11, 0xb5, 0xd5, 0x9a, 0x27, 0x77, 0x3f, 0x74, 0x47, 0x10, 0xb4,
-            0x76, 0xfb, 0xd3, 0x0f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x1b, 0xd6, 0x12, 0x9f, 0x96, 0x46, 0xaa, 0x21, 0xaf, 0x0d, 0x77,
-            0xe7, 0xb1, 0xcc, 0x97,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x13, 0x9a, 0x9d, 0x15, 0x93, 0xd5, 0x6e, 0x65, 0xe7, 0x10, 0xb2, 0xf3, 0x44,
-            0x75, 0x6b, 0x72, 0x1e,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2f, 0x8d, 0x49, 0x2d, 0x76, 0xa2, 0x2b, 0x68, 0x34, 0xf0, 0xb8,
-            0x8e, 0x2d, 0x40, 0x96,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x7d, 0xce, 0xbc, 0xfb, 0xab, 0xde, 0xdb, 0x47, 0xb2, 0xd7, 0x15, 0xb5, 0xba,
-            0xa6, 0x52, 0x0b, 0xff,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x05, 0x6a, 0xb5, 0x02, 0x82, 0xda, 0x42, 0x8d, 0x93, 0xb1, 0x7c,
-            0xbd, 0x1c, 0x81, 0x26,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x27, 0xcb, 0xee, 0xe6, 0x02, 0xbc, 0xb5, 0xa9, 0xed, 0x32, 0xc8, 0xd9, 0x44,
-            0x09, 0x12, 0xcb, 0x72,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x10, 0xb4, 0xe7, 0xbd, 0x9d, 0x6d, 0x91, 0xa5, 0x7b, 0x06, 0x95,
-            0xbe, 0x16, 0x6f, 0xfd,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x87, 0x75, 0x49, 0x9e, 0x69, 0xe8, 0xbd, 0x2c, 0x39, 0xaf, 0x33, 0xbd, 0x5f,
-            0xa0, 0xb4, 0x07, 0x9a,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x02, 0x42, 0x36, 0xbd, 0xa1, 0x26, 0x65, 0x0f, 0xb5, 0x22, 0x8c,
-            0xf4, 0x24, 0xa0, 0x87,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xb0, 0xeb, 0x1a, 0x86, 0x7b, 0x06, 0x85, 0x40, 0x66, 0x58, 0x9b, 0x96, 0x74,
-            0x55, 0x25, 0x9b, 0x32,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x23, 0x3c, 0xda, 0x92, 0x92, 0xbe, 0x02, 0xcf, 0xa2, 0xda, 0x9d,
-            0x0f, 0xc7, 0xb0, 0xea,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x50, 0x2e, 0xf1, 0x3d, 0x96, 0x52, 0x26, 0xa6, 0x34, 0xd0, 0x98, 0x15, 0x55,
-            0xe4, 0xa4, 0xda, 0x56,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x0c, 0xa0, 0xbc, 0x4b, 0x1c, 0xd9, 0xea, 0xdb, 0xbf, 0x49, 0xea,
-            0xe5, 0x6a, 0x99, 0xa4,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xc8, 0x0a, 0xd5, 0x6a, 0x53, 0x8d, 0x85, 0x2f, 0x75, 0xed, 0xa8, 0x58, 0xed,
-            0x1a, 0x61, 0x6c, 0x74,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x1a, 0x8a, 0x81, 0x8e, 0x6c, 0x61, 0xf6, 0x8c, 0xef, 0xa3, 0x29,
-            0xf2, 0xfa, 0xbc, 0x95,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xe4, 0xdf, 0x5f, 0xd7, 0xbb, 0x21, 0x27, 0x87, 0x74, 0x45, 0x80, 0xa7, 0x08,
-            0x88, 0xed, 0x7c, 0x9b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x03, 0x90, 0xb3, 0x6a, 0xd3, 0x40, 0xea, 0x81, 0x39, 0xf4, 0xde,
-            0xe6, 0x56, 0xb2, 0x1b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xd5, 0xe1, 0x3a, 0xf2, 0xe3, 0xd0, 0x99, 0x90, 0x68, 0xd0, 0x27, 0x0b, 0x5e,
-            0xa2, 0x90, 0x3f, 0xa5,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x02, 0xc6, 0xe4, 0xfa, 0x43, 0xda, 0xc4, 0xec, 0xaa, 0x89, 0xa6,
-            0x10, 0x20, 0x20, 0xcf,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x8c, 0x7e, 0x04, 0xe4, 0x36, 0xff, 0x48, 0xb1, 0xe5, 0xd8, 0x48, 0xf6, 0xc0,
-            0x0d, 0x1d, 0x8c, 0x51,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x11, 0x68, 0x1f, 0x11, 0x50, 0xc0, 0xd4, 0x1b, 0xf3, 0x9b, 0x26,
-            0x99, 0x0f, 0x07, 0x31,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xa3, 0x4e, 0xa0, 0x63, 0x66, 0x2a, 0x1f, 0x0f, 0x8a, 0x37, 0xb1, 0x7c, 0xb4,
-            0x25, 0xd2, 0x8c, 0x06,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x01, 0x21, 0x7b, 0x5d, 0x17, 0xa7, 0xae, 0x49, 0xd1, 0xd2, 0x8d,
-            0x3a, 0xf3, 0x91, 0x8a,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x76, 0xca, 0x08, 0x47, 0x5a, 0xa2, 0xda, 0xd9, 0xf8, 0x8f, 0x73, 0x37, 0xd8,
-            0x25, 0x16, 0x25, 0x66,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x18, 0x0d, 0xaa, 0xcf, 0x18, 0xd7, 0x62, 0x40, 0xe1, 0xc6, 0xc1,
-            0xf0, 0x9e, 0x14, 0xc9,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x5a, 0x9d, 0x21, 0x92, 0x4d, 0x8b, 0xaa, 0x82, 0x10, 0x89, 0xa6, 0x9e, 0xe9,
-            0x37, 0x87, 0xeb, 0x98,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x02, 0xc3, 0x5b, 0x82, 0xc8, 0x0f, 0xdc, 0xd4, 0xa5, 0x1c, 0x9f,
-            0x7b, 0xc2, 0xd8, 0x05,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xc1, 0x65, 0xcf, 0x21, 0x78, 0x34, 0x42, 0x79, 0x55, 0x67, 0x85, 0x1d, 0xbd,
-            0x1f, 0xc4, 0x5a, 0xba,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x13, 0x87, 0x1b, 0x93, 0x2b, 0xeb, 0x65, 0x69, 0xc0, 0x56, 0xfe,
-            0x0a, 0x8f, 0x9d, 0x39,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xf3, 0xd2, 0x5c, 0x5a, 0xcb, 0x34, 0xbd, 0x4d, 0x08, 0x47, 0x78, 0xdc, 0x75,
-            0x1b, 0x8e, 0xc2, 0xdb,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x29, 0x72, 0x19, 0xc9, 0x07, 0x7e, 0x5b, 0x03, 0x06, 0x32, 0x1c,
-            0x22, 0xea, 0xbf, 0xeb,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x5f, 0x4b, 0x0c, 0xc3, 0x43, 0x99, 0x85, 0x36, 0x71, 0xf9, 0xaf, 0x6d, 0x44,
-            0xa0, 0x8e, 0x43, 0x03,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x0c, 0x88, 0x34, 0xcd, 0xae, 0xb7, 0x90, 0xb7, 0xcb, 0x4c, 0xfd,
-            0x5e, 0xfa, 0x9e, 0xc8,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x01, 0xac, 0x5b, 0xfc, 0x0f, 0xa2, 0x22, 0x8a, 0xb7, 0x5d, 0xa1, 0xf6, 0x25,
-            0x61, 0x2d, 0x76, 0x6e,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2d, 0xbd, 0x03, 0x47, 0x89, 0x85, 0x66, 0xb5, 0xf1, 0x36, 0x54,
-            0x56, 0x02, 0xb2, 0xd8,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xab, 0xe9, 0x42, 0x00, 0x1c, 0x12, 0x08, 0x34, 0x8d, 0x85, 0x0f, 0xaa, 0x81,
-            0x06, 0xa0, 0x53, 0x72,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x02, 0x66, 0xfe, 0xef, 0x2f, 0x32, 0xe5, 0x4a, 0xb1, 0xf7, 0x01,
-            0xaf, 0x2c, 0x37, 0x2b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x65, 0xba, 0xd7, 0x38, 0x28, 0xc4, 0xe2, 0x71, 0x51, 0x90, 0x36, 0x99, 0x2a,
-            0x63, 0x38, 0x8c, 0x4f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x02, 0x76, 0x95, 0xb0, 0x14, 0xe1, 0x08, 0x1b, 0x7f, 0xd2, 0xe9,
-            0x29, 0x9a, 0x78, 0x55,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x17, 0xba, 0x85, 0x1f, 0x3d, 0xec, 0x83, 0xf3, 0x56, 0x83, 0x0d, 0xa0, 0x0d,
-            0xf0, 0xec, 0x8a, 0x12,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2f, 0x05, 0xdc, 0x92, 0x1d, 0xd8, 0xaa, 0x82, 0xd7, 0xe9, 0x27,
-            0xa3, 0xe8, 0x6f, 0x17,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x88, 0xca, 0x51, 0x89, 0x66, 0xb2, 0x65, 0xf6, 0xc3, 0x7b, 0x89, 0x11, 0xa3,
-            0x49, 0x81, 0x65, 0x24,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x09, 0xbd, 0x10, 0x80, 0x19, 0xc4, 0x3d, 0xfd, 0x05, 0x3b, 0xc1,
-            0xb4, 0xa7, 0xcf, 0x67,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xb1, 0x7a, 0xb3, 0xf2, 0x1c, 0x26, 0x26, 0xed, 0xf6, 0x88, 0x20, 0xe7, 0x7e,
-            0x7d, 0x6a, 0x6b, 0x8f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x05, 0xc5, 0x1f, 0x54, 0x28, 0xf5, 0x00, 0xc4, 0x18, 0xc3, 0xd3,
-            0x03, 0x6d, 0x59, 0xc3,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x19, 0x90, 0x53, 0x61, 0xd6, 0x67, 0x84, 0x6d, 0x9f, 0xe3, 0xfb, 0x57, 0xd2,
-            0xd7, 0xcf, 0xab, 0xe0,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x1f, 0x5a, 0x13, 0xe1, 0x5b, 0xff, 0x10, 0x5e, 0x44, 0xea, 0x84,
-            0x09, 0xf5, 0xc9, 0x0b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x22, 0x2e, 0x65, 0x53, 0x03, 0x59, 0x53, 0x03, 0x7a, 0x67, 0x9a, 0xb2, 0x72,
-            0xfa, 0x31, 0x05, 0xb9,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x21, 0xba, 0x3a, 0xba, 0x55, 0x1d, 0x4f, 0x6e, 0xb1, 0x22, 0x4d,
-            0xdc, 0x6b, 0x1b, 0xd3,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x12, 0x56, 0xc7, 0x09, 0xea, 0xfa, 0xe2, 0x9d, 0x6d, 0x2c, 0x39, 0xa3, 0x4b,
-            0x1b, 0x77, 0xc8, 0x6c,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x20, 0x21, 0xee, 0x9b, 0xf4, 0x03, 0x60, 0x08, 0xc7, 0xc5, 0x36,
-            0x0b, 0x19, 0x26, 0x6d,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xc9, 0x06, 0xe4, 0x87, 0x17, 0x4a, 0xcb, 0x4f, 0xfd, 0x41, 0x5b, 0x2d, 0x78,
-            0xe0, 0x50, 0x72, 0xe6,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x29, 0x2e, 0xc6, 0xf9, 0x35, 0xca, 0xa1, 0xdf, 0x0c, 0x8f, 0x63,
-            0x21, 0x2e, 0x01, 0x16,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0xa4, 0x75, 0x5d, 0x42, 0xee, 0x0b, 0x5e, 0xe4, 0x32, 0x3e, 0x4a, 0x3c, 0x8d,
-            0xd0, 0x12, 0xd1, 0x01,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x1d, 0x30, 0x47, 0xe5, 0xfa, 0xf3, 0x96, 0xec, 0xdf, 0xf4, 0x62,
-            0x11, 0x99, 0x35, 0x58,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x16, 0x0e, 0x17, 0xaf, 0xf3, 0x20, 0x5e, 0xbc, 0x50, 0xdd, 0x3e, 0x6a, 0xde,
-            0x80, 0x82, 0xc4, 0x59,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x0a, 0x0a, 0x05, 0x73, 0x28, 0xda, 0x58, 0x33, 0x1a, 0x5b, 0xda,
-            0xc4, 0x19, 0x00, 0xc5,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x2f, 0x71, 0x67, 0x70, 0x10, 0xde, 0x46, 0x6c, 0xea, 0x77, 0x7c, 0x91, 0x0d,
-            0x9a, 0x5d, 0x4d, 0x6e,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2f, 0x1f, 0x65, 0x79, 0xac, 0x43, 0x5c, 0xcd, 0x3b, 0xec, 0xb1,
-            0x1c, 0xac, 0x21, 0xa4,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x7b, 0x73, 0xe8, 0x54, 0xce, 0x6c, 0x76, 0x37, 0x32, 0x12, 0x2a, 0x3b, 0x92,
-            0x3b, 0xc6, 0x79, 0x7b,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x27, 0x45, 0x6b, 0x3a, 0x66, 0x6f, 0xf2, 0x4c, 0x64, 0x52, 0x65,
-            0x74, 0x37, 0x51, 0x8f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x27, 0x5a, 0x33, 0x32, 0x5b, 0x05, 0xb2, 0xdd, 0x53, 0xc3, 0x36, 0x62, 0x36,
-            0x9b, 0xcd, 0xc4, 0xe0,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2e, 0xcb, 0xc0, 0xdb, 0x4a, 0xe7, 0x2d, 0x05, 0xdb, 0x96, 0xeb,
-            0x72, 0x03, 0x4b, 0x26,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x01,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x02,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x4a, 0x4c, 0x8d, 0x68, 0x86, 0x25, 0x6a, 0xde, 0xca, 0x29, 0x36, 0x61, 0xe0,
-            0x4f, 0x1a, 0x3d, 0xdf,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x1a, 0xeb, 0xf5, 0x30, 0x57, 0xbe, 0x46, 0x7f, 0x5c, 0x3e, 0xd0,
-            0xf8, 0x8d, 0x90, 0x60,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x73, 0x24, 0x99, 0x08, 0xa1, 0x60, 0xef, 0xa4, 0x92, 0x1b, 0xc0, 0xe5, 0xc1,
-            0x6d, 0xa5, 0x8b, 0x6f,
-        ],
-        [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x2b, 0xb5, 0xfc, 0xc2, 0x13, 0x32, 0xb8, 0x35, 0x21, 0xc6, 0x35,
-            0x99, 0x55, 0x7c, 0x64,
-        ],
-    ];
+    // Raw VK, one 32-byte big-endian field per line
+    const VK_BYTES: &[u8; VK_LEN * 32] = b"\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x20\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xc0\x25\x0c\x6f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x09\x61\x1f\x59\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x15\x6e\x03\x55\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x90\x0a\x3f\x7d\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xa9\xb5\x94\x48\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xd1\xdd\xe7\x84\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x6f\xd6\x34\x35\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x63\xa7\x0e\xcc\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xde\x0f\x83\xb4\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x04\xf0\x35\xd6\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xa2\xe6\x3c\x09\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x7b\x48\x18\x78\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x9d\x34\x45\x2b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x98\x82\xa0\x17\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x8e\xd2\xd1\x6e\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xfc\x8b\x95\xf5\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x6f\xdc\xe4\x58\xb0\x8b\x2e\xda\xf7\x37\x52\x0a\x25\xce\x0b\xa9\x96\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x21\x8b\x93\xf3\xfa\xb3\x03\xe8\x4c\x3e\x8b\x20\x90\xd4\x81\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x94\xb8\x93\xc3\x61\x18\x5f\xc1\x26\x8a\x00\xee\xe4\x2d\x86\x52\xcd\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x07\x92\xc7\x4b\xa6\x60\x04\xeb\xf2\x00\x2e\x06\x15\xbc\xfd\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x5f\x31\xbc\x77\x68\xbf\x56\x85\xd4\x4b\xd9\xb8\x54\xbe\x2b\x70\xa4\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x24\x91\x36\xba\xa4\x36\xc0\x1e\xd3\x49\xfa\xaf\x73\x80\x55\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xbb\xf7\xbc\xa4\xda\xaa\x5d\x71\x6f\xd6\xc8\x0d\x8b\x9a\x9f\xd2\x2a\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2c\x7f\x6d\xfb\x6e\x2b\x2e\xc3\x71\xb4\xe0\xdd\x99\xd5\xc5\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x28\xed\xd1\xa7\xe4\x6c\x84\x0d\x9c\x94\x3f\xdf\x45\x52\x1c\x64\xce\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x04\x3d\x06\x3b\x13\x0a\xdf\xb3\x73\x42\xaf\x45\xd0\x15\x5a\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x93\x30\x95\x2a\xe7\x4c\x57\x3d\x16\x86\xd9\xcb\x4a\x00\x73\x38\x54\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x26\x15\x22\xc4\x08\x93\x30\x64\x6a\xff\x96\x73\x61\x94\x94\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xfc\x80\xea\x49\x6f\x69\x5e\x68\x1e\x5b\x2e\x81\xe2\x89\x96\xa8\x56\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x18\xbc\x17\xb1\x96\x9a\xd2\x45\xf8\xca\xa3\xf4\x67\x17\x6d\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xe3\x9e\xfa\x72\x16\x44\xb3\x4f\xe5\xe4\x73\xe5\x69\x13\xe1\xc8\x10\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x21\xf7\x4c\x19\x2e\xb4\x15\x02\x71\xb8\x54\xeb\xa3\x99\x4f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x50\x80\xcd\x58\x34\xb1\x58\x42\xe0\xab\xec\xb6\x82\xb8\x1a\xca\x71\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x15\x27\x9f\x91\x39\xfe\x92\x4c\xd7\xac\x26\x82\x34\x73\x1b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x85\x15\x24\x54\x05\xe6\xef\xfd\x84\x81\x8a\x49\xe5\x45\x83\xcd\x9f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x27\xe3\x77\xf2\x75\x0e\x22\x16\x06\x60\x86\xd9\x2b\xcd\x74\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xed\xa1\x13\x99\xdc\x21\xe2\x3d\xf4\xbe\xe4\xb5\xcd\x0b\xec\x65\x9f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1e\xec\x24\x71\x54\xce\xd5\xc2\x9b\x08\x36\x52\x8d\x7c\x19\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0b\xdb\x0c\x0a\xc4\xb0\x54\xa9\x05\xb0\x3b\x9d\x53\xbe\xbe\xf4\x04\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x10\x7c\xc3\x82\xfd\xee\x2f\x65\x30\xd3\x9b\x07\x2a\x2b\xc5\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x72\xbd\x9f\x54\xe6\xe9\xb5\x71\x29\xce\x99\x5b\x19\xce\xc0\xc9\x0f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x24\x53\xe0\x56\xdc\x17\x9b\xdc\x91\x64\xde\x8e\x36\x54\xed\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xa0\x43\x13\x9c\x10\xe1\x84\x0d\x9a\x1a\x7b\xba\x09\x89\x61\x8a\x5a\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x15\xbc\x46\x80\xdb\x7e\xb8\x10\x0d\x97\xa8\xcf\xae\xab\xcd\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xcb\x39\x5b\x82\x7e\x90\x79\xe9\x99\xb6\xc1\x28\xd9\xb0\x83\xc3\x95\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x17\x97\xe3\xe7\xee\x9e\x4f\x42\xb4\x2b\xd3\x75\xf1\x3f\x2c\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xef\xac\xf4\x59\x41\xc3\xef\xe9\x3c\x35\x68\xbd\xe1\xe0\x8e\x19\x19\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x10\x1a\x60\xef\xaa\xb1\xc8\x56\x4a\xdd\x45\xd4\x1b\x91\x47\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x38\x96\xea\x79\x3e\x6b\x3f\x6a\x14\x21\x8d\x47\x65\x34\x10\x96\x10\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0e\x84\x09\x0a\xdd\x56\xf2\x50\x0a\xb5\x18\xc6\x55\xca\xe6\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x65\xdf\x44\x6f\xdd\xdb\xa9\x72\xf3\xc4\x41\x4a\xd3\xc9\x01\xf4\xf9\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2b\x78\xa5\x84\xbd\x6a\xe8\x8c\xf4\xec\x7c\x65\xc9\x0e\x0b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x94\xe6\x11\xb5\xd5\x9a\x27\x77\x3f\x74\x47\x10\xb4\x76\xfb\xd3\x0f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1b\xd6\x12\x9f\x96\x46\xaa\x21\xaf\x0d\x77\xe7\xb1\xcc\x97\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x13\x9a\x9d\x15\x93\xd5\x6e\x65\xe7\x10\xb2\xf3\x44\x75\x6b\x72\x1e\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2f\x8d\x49\x2d\x76\xa2\x2b\x68\x34\xf0\xb8\x8e\x2d\x40\x96\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x7d\xce\xbc\xfb\xab\xde\xdb\x47\xb2\xd7\x15\xb5\xba\xa6\x52\x0b\xff\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x05\x6a\xb5\x02\x82\xda\x42\x8d\x93\xb1\x7c\xbd\x1c\x81\x26\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x27\xcb\xee\xe6\x02\xbc\xb5\xa9\xed\x32\xc8\xd9\x44\x09\x12\xcb\x72\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x10\xb4\xe7\xbd\x9d\x6d\x91\xa5\x7b\x06\x95\xbe\x16\x6f\xfd\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x87\x75\x49\x9e\x69\xe8\xbd\x2c\x39\xaf\x33\xbd\x5f\xa0\xb4\x07\x9a\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\x42\x36\xbd\xa1\x26\x65\x0f\xb5\x22\x8c\xf4\x24\xa0\x87\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xb0\xeb\x1a\x86\x7b\x06\x85\x40\x66\x58\x9b\x96\x74\x55\x25\x9b\x32\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x23\x3c\xda\x92\x92\xbe\x02\xcf\xa2\xda\x9d\x0f\xc7\xb0\xea\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x50\x2e\xf1\x3d\x96\x52\x26\xa6\x34\xd0\x98\x15\x55\xe4\xa4\xda\x56\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0c\xa0\xbc\x4b\x1c\xd9\xea\xdb\xbf\x49\xea\xe5\x6a\x99\xa4\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xc8\x0a\xd5\x6a\x53\x8d\x85\x2f\x75\xed\xa8\x58\xed\x1a\x61\x6c\x74\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1a\x8a\x81\x8e\x6c\x61\xf6\x8c\xef\xa3\x29\xf2\xfa\xbc\x95\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xe4\xdf\x5f\xd7\xbb\x21\x27\x87\x74\x45\x80\xa7\x08\x88\xed\x7c\x9b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x03\x90\xb3\x6a\xd3\x40\xea\x81\x39\xf4\xde\xe6\x56\xb2\x1b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xd5\xe1\x3a\xf2\xe3\xd0\x99\x90\x68\xd0\x27\x0b\x5e\xa2\x90\x3f\xa5\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\xc6\xe4\xfa\x43\xda\xc4\xec\xaa\x89\xa6\x10\x20\x20\xcf\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x8c\x7e\x04\xe4\x36\xff\x48\xb1\xe5\xd8\x48\xf6\xc0\x0d\x1d\x8c\x51\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x11\x68\x1f\x11\x50\xc0\xd4\x1b\xf3\x9b\x26\x99\x0f\x07\x31\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xa3\x4e\xa0\x63\x66\x2a\x1f\x0f\x8a\x37\xb1\x7c\xb4\x25\xd2\x8c\x06\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x21\x7b\x5d\x17\xa7\xae\x49\xd1\xd2\x8d\x3a\xf3\x91\x8a\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x76\xca\x08\x47\x5a\xa2\xda\xd9\xf8\x8f\x73\x37\xd8\x25\x16\x25\x66\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x18\x0d\xaa\xcf\x18\xd7\x62\x40\xe1\xc6\xc1\xf0\x9e\x14\xc9\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x5a\x9d\x21\x92\x4d\x8b\xaa\x82\x10\x89\xa6\x9e\xe9\x37\x87\xeb\x98\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\xc3\x5b\x82\xc8\x0f\xdc\xd4\xa5\x1c\x9f\x7b\xc2\xd8\x05\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xc1\x65\xcf\x21\x78\x34\x42\x79\x55\x67\x85\x1d\xbd\x1f\xc4\x5a\xba\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x13\x87\x1b\x93\x2b\xeb\x65\x69\xc0\x56\xfe\x0a\x8f\x9d\x39\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xf3\xd2\x5c\x5a\xcb\x34\xbd\x4d\x08\x47\x78\xdc\x75\x1b\x8e\xc2\xdb\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x29\x72\x19\xc9\x07\x7e\x5b\x03\x06\x32\x1c\x22\xea\xbf\xeb\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x5f\x4b\x0c\xc3\x43\x99\x85\x36\x71\xf9\xaf\x6d\x44\xa0\x8e\x43\x03\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0c\x88\x34\xcd\xae\xb7\x90\xb7\xcb\x4c\xfd\x5e\xfa\x9e\xc8\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\xac\x5b\xfc\x0f\xa2\x22\x8a\xb7\x5d\xa1\xf6\x25\x61\x2d\x76\x6e\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2d\xbd\x03\x47\x89\x85\x66\xb5\xf1\x36\x54\x56\x02\xb2\xd8\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xab\xe9\x42\x00\x1c\x12\x08\x34\x8d\x85\x0f\xaa\x81\x06\xa0\x53\x72\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\x66\xfe\xef\x2f\x32\xe5\x4a\xb1\xf7\x01\xaf\x2c\x37\x2b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x65\xba\xd7\x38\x28\xc4\xe2\x71\x51\x90\x36\x99\x2a\x63\x38\x8c\x4f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\x76\x95\xb0\x14\xe1\x08\x1b\x7f\xd2\xe9\x29\x9a\x78\x55\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x17\xba\x85\x1f\x3d\xec\x83\xf3\x56\x83\x0d\xa0\x0d\xf0\xec\x8a\x12\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2f\x05\xdc\x92\x1d\xd8\xaa\x82\xd7\xe9\x27\xa3\xe8\x6f\x17\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x88\xca\x51\x89\x66\xb2\x65\xf6\xc3\x7b\x89\x11\xa3\x49\x81\x65\x24\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x09\xbd\x10\x80\x19\xc4\x3d\xfd\x05\x3b\xc1\xb4\xa7\xcf\x67\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xb1\x7a\xb3\xf2\x1c\x26\x26\xed\xf6\x88\x20\xe7\x7e\x7d\x6a\x6b\x8f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x05\xc5\x1f\x54\x28\xf5\x00\xc4\x18\xc3\xd3\x03\x6d\x59\xc3\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x19\x90\x53\x61\xd6\x67\x84\x6d\x9f\xe3\xfb\x57\xd2\xd7\xcf\xab\xe0\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1f\x5a\x13\xe1\x5b\xff\x10\x5e\x44\xea\x84\x09\xf5\xc9\x0b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x22\x2e\x65\x53\x03\x59\x53\x03\x7a\x67\x9a\xb2\x72\xfa\x31\x05\xb9\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x21\xba\x3a\xba\x55\x1d\x4f\x6e\xb1\x22\x4d\xdc\x6b\x1b\xd3\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x12\x56\xc7\x09\xea\xfa\xe2\x9d\x6d\x2c\x39\xa3\x4b\x1b\x77\xc8\x6c\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x20\x21\xee\x9b\xf4\x03\x60\x08\xc7\xc5\x36\x0b\x19\x26\x6d\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xc9\x06\xe4\x87\x17\x4a\xcb\x4f\xfd\x41\x5b\x2d\x78\xe0\x50\x72\xe6\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x29\x2e\xc6\xf9\x35\xca\xa1\xdf\x0c\x8f\x63\x21\x2e\x01\x16\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xa4\x75\x5d\x42\xee\x0b\x5e\xe4\x32\x3e\x4a\x3c\x8d\xd0\x12\xd1\x01\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1d\x30\x47\xe5\xfa\xf3\x96\xec\xdf\xf4\x62\x11\x99\x35\x58\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x16\x0e\x17\xaf\xf3\x20\x5e\xbc\x50\xdd\x3e\x6a\xde\x80\x82\xc4\x59\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x0a\x0a\x05\x73\x28\xda\x58\x33\x1a\x5b\xda\xc4\x19\x00\xc5\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2f\x71\x67\x70\x10\xde\x46\x6c\xea\x77\x7c\x91\x0d\x9a\x5d\x4d\x6e\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2f\x1f\x65\x79\xac\x43\x5c\xcd\x3b\xec\xb1\x1c\xac\x21\xa4\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x7b\x73\xe8\x54\xce\x6c\x76\x37\x32\x12\x2a\x3b\x92\x3b\xc6\x79\x7b\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x27\x45\x6b\x3a\x66\x6f\xf2\x4c\x64\x52\x65\x74\x37\x51\x8f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x27\x5a\x33\x32\x5b\x05\xb2\xdd\x53\xc3\x36\x62\x36\x9b\xcd\xc4\xe0\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2e\xcb\xc0\xdb\x4a\xe7\x2d\x05\xdb\x96\xeb\x72\x03\x4b\x26\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x4a\x4c\x8d\x68\x86\x25\x6a\xde\xca\x29\x36\x61\xe0\x4f\x1a\x3d\xdf\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1a\xeb\xf5\x30\x57\xbe\x46\x7f\x5c\x3e\xd0\xf8\x8d\x90\x60\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x73\x24\x99\x08\xa1\x60\xef\xa4\x92\x1b\xc0\xe5\xc1\x6d\xa5\x8b\x6f\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x2b\xb5\xfc\xc2\x13\x32\xb8\x35\x21\xc6\x35\x99\x55\x7c\x64";
+    const VK: [[u8; 32]; VK_LEN] = split_vk_fields(VK_BYTES);
+
+    /// Split the raw VK into 32-byte fields at compile time
+    const fn split_vk_fields(bytes: &[u8; VK_LEN * 32]) -> [[u8; 32]; VK_LEN] {
+        let mut fields = [[0u8; 32]; VK_LEN];
+        let mut i = 0;
+        while i < VK_LEN * 32 {
+            fields[i / 32][i % 32] = bytes[i];
+            i += 1;
+        }
+        fields
+    }
 
     #[ink(storage)]
-    pub struct Verifier {}
+    pub struct Verifier {
+        owner: H160,
+        paused: bool,
+        /// VK installed with `set_vk`; takes precedence over the embedded `VK`.
+        /// Lazy so the 4 KiB key is only read by the messages that need it.
+        stored_vk: Lazy<Vec<[u8; 32]>>,
+        /// `false` for `new_empty` deployments, which have no VK until `set_vk`
+        uses_embedded_vk: bool,
+    }
+
+    /// Emitted when the owner stops the verifier from accepting proofs
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        by: H160,
+    }
+
+    /// Emitted when the owner resumes verification
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        by: H160,
+    }
+
+    /// Emitted when the owner installs a new verification key
+    #[ink(event)]
+    pub struct VkSet {
+        #[ink(topic)]
+        by: H160,
+        vk_hash: [u8; 32],
+    }
 
     impl Verifier {
+        /// Deploy with the embedded VK, refused if it fails `self_test`
         #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {}
-        }
-
-        /// We need to reconstruct the VerificationKey from the flat VK array
-        /// The VK array contains 128 field elements (32 bytes each)
-        /// Layout: [metadata fields, then G1 points as (x, y) pairs]
-        /// Index 0: circuit_size (N)
-        /// Index 1: log_circuit_size (LOG_N)
-        /// Index 2: public_inputs_size
-        /// Index 3: num_public_inputs (sometimes present, may be same as index 2)
-        /// 
-        /// Then G1 points as (x, y) pairs:
-        /// The order follows the Solidity VK structure
-        fn reconstruct_vk(&self) -> VerifierResult<VerificationKey> {
-            // Helper to extract a G1Point from VK array (2 field elements: x, y)
-            let get_g1_point = |idx: usize| -> G1Point {
-                G1Point {
-                    x: self.vk_field_to_fr(&VK[idx]),
-                    y: self.vk_field_to_fr(&VK[idx + 1]),
-                }
-            };
+        pub fn new() -> Result<Self, VerifierError> {
+            Self::checked_vk(&VK)?;
+            Ok(Self {
+                owner: Self::env().caller(),
+                paused: false,
+                stored_vk: Lazy::new(),
+                uses_embedded_vk: true,
+            })
+        }
 
-            // Read metadata fields (first 3 fields)
-            let circuit_size = self.vk_field_to_fr(&VK[0]);
-            let log_circuit_size = self.vk_field_to_fr(&VK[1]);
-            let public_inputs_size = self.vk_field_to_fr(&VK[2]);
+        /// Deploy without a VK, to be installed later with `set_vk`.
+        /// `verify` fails with `InvalidVerificationKey` until then.
+        #[ink(constructor)]
+        pub fn new_empty() -> Self {
+            Self {
+                owner: Self::env().caller(),
+                paused: false,
+                stored_vk: Lazy::new(),
+                uses_embedded_vk: false,
+            }
+        }
 
-            // Validate metadata
-            if circuit_size.is_zero() {
+        /// Owner-only: replace the verification key with `vk`, which must be
+        /// `VK_LEN` fields in the same layout as the embedded one and pass
+        /// `self_test`
+        #[ink(message)]
+        pub fn set_vk(&mut self, vk: Vec<u8>) -> Result<(), VerifierError> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(VerifierError::NotOwner);
+            }
+            if vk.len() != VK_LEN * 32 {
                 return Err(VerifierError::InvalidVerificationKey);
             }
 
-            // G1 points start at index 3
-            // Skip index 3 if it's a duplicate of public_inputs_size
-            // Check if index 3 equals index 2 - if so, skip it
-            let g1_start = if self.vk_field_to_fr(&VK[3]) == public_inputs_size { 4 } else { 3 };
-            
-            let idx = g1_start;
-            
-            // Extract all G1 points in the order specified by the Solidity VK
-            // Based on the Solidity VK, the order is:
-            // qm, qc, ql, qr, qo, q4, qLookup, qArith, qDeltaRange, qElliptic, qAux,
-            // qPoseidon2External, qPoseidon2Internal, s1, s2, s3, s4,
-            // id1, id2, id3, id4, t1, t2, t3, t4, lagrangeFirst, lagrangeLast
-            Ok(VerificationKey {
-                circuit_size,
-                log_circuit_size,
-                public_inputs_size,
-                qm: get_g1_point(idx),
-                qc: get_g1_point(idx + 2),
-                ql: get_g1_point(idx + 4),
-                qr: get_g1_point(idx + 6),
-                qo: get_g1_point(idx + 8),
-                q4: get_g1_point(idx + 10),
-                q_lookup: get_g1_point(idx + 12),
-                q_arith: get_g1_point(idx + 14),
-                q_delta_range: get_g1_point(idx + 16),
-                q_elliptic: get_g1_point(idx + 18),
-                q_aux: get_g1_point(idx + 20),
-                q_poseidon2_external: get_g1_point(idx + 22),
-                q_poseidon2_internal: get_g1_point(idx + 24),
-                s1: get_g1_point(idx + 26),
-                s2: get_g1_point(idx + 28),
-                s3: get_g1_point(idx + 30),
-                s4: get_g1_point(idx + 32),
-                id1: get_g1_point(idx + 34),
-                id2: get_g1_point(idx + 36),
-                id3: get_g1_point(idx + 38),
-                id4: get_g1_point(idx + 40),
-                t1: get_g1_point(idx + 42),
-                t2: get_g1_point(idx + 44),
-                t3: get_g1_point(idx + 46),
-                t4: get_g1_point(idx + 48),
-                lagrange_first: get_g1_point(idx + 50),
-                lagrange_last: get_g1_point(idx + 52),
-            })
+            let fields: Vec<[u8; 32]> = vk
+                .chunks_exact(32)
+                .map(|chunk| chunk.try_into().expect("chunk is 32 bytes"))
+                .collect();
+            Self::checked_vk(&fields)?;
+            self.stored_vk.set(&fields);
+            self.env().emit_event(VkSet { by: caller, vk_hash: Self::hash_fields(&vk) });
+            Ok(())
+        }
+
+        /// Owner-only switch to stop `verify` from accepting any proof,
+        /// e.g. while a soundness issue is investigated
+        #[ink(message)]
+        pub fn set_paused(&mut self, paused: bool) -> Result<(), VerifierError> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(VerifierError::NotOwner);
+            }
+            if self.paused != paused {
+                self.paused = paused;
+                if paused {
+                    self.env().emit_event(Paused { by: caller });
+                } else {
+                    self.env().emit_event(Unpaused { by: caller });
+                }
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /// The VK in effect as a `VerificationKey`. The flat fields are in bb's
+        /// layout, see `VerificationKey::from_bb_fields`.
+        fn reconstruct_vk(&self) -> VerifierResult<VerificationKey> {
+            self.reconstruct_vk_from(&self.vk_fields()?)
+        }
+
+        fn reconstruct_vk_from(&self, fields: &[[u8; 32]]) -> VerifierResult<VerificationKey> {
+            VerificationKey::from_bb_fields(fields)
+        }
+
+        /// `fields` as a VK, if it also passes `self_test`. Run whenever a VK is
+        /// installed rather than on every `verify`, which only needs the structure.
+        fn checked_vk(fields: &[[u8; 32]]) -> VerifierResult<VerificationKey> {
+            let vk = VerificationKey::from_bb_fields(fields)?;
+            self_test(&vk)?;
+            Ok(vk)
+        }
+
+        /// Returns `(circuit_size, num_public_inputs, vk_hash)` for the active VK
+        /// so callers can check they are talking to the verifier for their circuit.
+        /// `vk_hash` is the Keccak-256 of the raw VK bytes. Fails with
+        /// `InvalidVerificationKey` if no VK is set or a size does not fit a `u64`.
+        #[ink(message)]
+        pub fn circuit_info(&self) -> Result<(u64, u64, [u8; 32]), VerifierError> {
+            let fields = self.vk_fields()?;
+            let vk = self.reconstruct_vk_from(&fields)?;
+            let to_u64 = |value: U256| {
+                u64::try_from(value).map_err(|_| VerifierError::InvalidVerificationKey)
+            };
+            Ok((
+                to_u64(vk.circuit_size)?,
+                to_u64(vk.public_inputs_size)?,
+                Self::hash_fields(fields.as_flattened()),
+            ))
+        }
+
+        /// The VK in effect: the one from `set_vk` if any, else the embedded one.
+        /// Each call reads the stored key from contract storage.
+        fn vk_fields(&self) -> VerifierResult<Cow<'static, [[u8; 32]]>> {
+            match self.stored_vk.get() {
+                Some(fields) => Ok(Cow::Owned(fields)),
+                None if self.uses_embedded_vk => Ok(Cow::Borrowed(&VK)),
+                None => Err(VerifierError::InvalidVerificationKey),
+            }
+        }
+
+        /// Keccak-256 over the concatenated VK field elements
+        fn hash_fields(bytes: &[u8]) -> [u8; 32] {
+            let mut output = <Keccak256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Keccak256>(bytes, &mut output);
+            output
         }
 
         /// Verifies an UltraHonk proof.
         #[ink(message)]
         pub fn verify(&self, proof: Vec<u8>, public_inputs: Vec<Vec<u8>>) -> Result<bool, VerifierError> {
+            self.verify_staged(proof, public_inputs).1
+        }
+
+        /// Same as `verify`, but also reports the last stage that completed
+        /// successfully (`None` if the proof could not even be parsed).
+        #[ink(message)]
+        pub fn verify_staged(
+            &self,
+            proof: Vec<u8>,
+            public_inputs: Vec<Vec<u8>>,
+        ) -> (Option<VerifyStage>, Result<bool, VerifierError>) {
+            let mut stage = None;
+            let result = self
+                .run_verification(&proof, &public_inputs, &mut stage)
+                .map(|_| true);
+            (stage, result)
+        }
+
+        /// Same checks and errors as `verify`, but on success returns the public
+        /// inputs as the canonical field elements the transcript was built from,
+        /// so a caller can bind later logic to exactly what was verified.
+        pub fn verify_and_extract(&self, proof: &[u8], public_inputs: &[Vec<u8>]) -> VerifierResult<Vec<Fr>> {
+            self.run_verification(proof, public_inputs, &mut None)
+        }
+
+        /// Verifies `proof` against Fiat-Shamir challenges the caller already
+        /// derived (e.g. a recursion coordinator) instead of generating them.
+        /// The transcript is taken as given: it must be the one
+        /// `Transcript::generate` yields for this proof, `vk` and public inputs,
+        /// whose only other use here is the count check against `vk`. One with
+        /// a zero or non-canonical challenge fails with `InvalidTranscript`.
+        pub fn verify_with_transcript(
+            &self,
+            vk: &VerificationKey,
+            proof: &Proof,
+            public_inputs: &[Fr],
+            transcript: &Transcript,
+        ) -> VerifierResult<bool> {
+            if self.paused {
+                return Err(VerifierError::Paused);
+            }
+
+            if !transcript.has_challenges_for(vk.log_n()?) {
+                return Err(VerifierError::InvalidTranscript);
+            }
+
+            vk.check_public_inputs_len(public_inputs.len())?;
+            let pairing_points = Self::pairing_point_inputs(vk, public_inputs)?;
+
+            let opening = &proof.opening;
+            self.validate_proof_points(opening)?;
+            self.verify_from_transcript(
+                opening,
+                |round| proof.sumcheck_univariates[round],
+                vk,
+                transcript,
+                pairing_points,
+                &mut None,
+            )?;
+            Ok(true)
+        }
+
+        /// Runs every stage, returning the decoded public inputs on success
+        fn run_verification(
+            &self,
+            proof: &[u8],
+            public_inputs: &[Vec<u8>],
+            stage: &mut Option<VerifyStage>,
+        ) -> VerifierResult<Vec<Fr>> {
+            self.run_verification_against(None, proof, public_inputs, stage)
+        }
+
+        /// `run_verification` against `vk_fields` instead of the contract's own
+        /// VK when given, as router contracts do per circuit
+        fn run_verification_against(
+            &self,
+            vk_fields: Option<&[[u8; 32]]>,
+            proof: &[u8],
+            public_inputs: &[Vec<u8>],
+            stage: &mut Option<VerifyStage>,
+        ) -> VerifierResult<Vec<Fr>> {
+            if self.paused {
+                return Err(VerifierError::Paused);
+            }
+
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("verify", proof_len = proof.len()).entered();
+
             // Parse the proof
-            let parsed_proof = self.parse_proof(&proof)
+            let parsed_proof = self.parse_proof(proof)
                 .ok_or(VerifierError::InvalidProofFormat)?;
 
             // Load verification key
-            let vk = self.reconstruct_vk()?;
+            let vk = match vk_fields {
+                Some(fields) => self.reconstruct_vk_from(fields)?,
+                None => self.reconstruct_vk()?,
+            };
+
+            self.verify_opening(
+                &vk,
+                &parsed_proof.opening,
+                |round| parsed_proof.sumcheck_univariates[round],
+                public_inputs,
+                stage,
+            )
+        }
+
+        /// Every stage after parsing, with the sumcheck univariates supplied
+        /// per round by `univariate`
+        fn verify_opening(
+            &self,
+            vk: &VerificationKey,
+            proof: &ProofOpening,
+            univariate: impl Fn(usize) -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH],
+            public_inputs: &[Vec<u8>],
+            stage: &mut Option<VerifyStage>,
+        ) -> VerifierResult<Vec<Fr>> {
+            vk.log_n().inspect_err(|_| {
+                debug_event!(
+                    log_circuit_size = ?vk.log_circuit_size,
+                    max = CONST_PROOF_SIZE_LOG_N,
+                    "VK log_circuit_size exceeds CONST_PROOF_SIZE_LOG_N"
+                );
+            })?;
 
             // Validate public inputs size
-            self.validate_public_inputs(&public_inputs, &vk)?;
+            let public_input_values = self.validate_public_inputs(public_inputs, vk)?;
+            let pairing_points = Self::pairing_point_inputs(vk, &public_input_values)?;
+            *stage = Some(VerifyStage::Parsed);
+            debug_event!(stage = ?stage, "verification stage passed");
+
+            self.validate_proof_points(proof)?;
+            *stage = Some(VerifyStage::PointsValidated);
+            debug_event!(stage = ?stage, "verification stage passed");
 
             // Generate transcript
-            let transcript = Transcript::generate(
-                &parsed_proof,
-                &public_inputs,
-                vk.circuit_size,
-                vk.public_inputs_size,
+            let transcript = Transcript::generate_streaming(
+                proof,
+                &univariate,
+                public_inputs,
+                vk,
                 U256::one(), //pub_inputs_offset
+            )?;
+            *stage = Some(VerifyStage::TranscriptGenerated);
+            debug_event!(
+                stage = ?stage,
+                beta = ?transcript.relation_parameters.beta,
+                gamma = ?transcript.relation_parameters.gamma,
+                "verification stage passed"
             );
 
-            // Compute public input delta
-            let public_input_delta = self.compute_public_input_delta(
-                &public_inputs,
-                transcript.relation_parameters.beta,
-                transcript.relation_parameters.gamma,
-                vk.circuit_size,
-            )?;
+            self.verify_from_transcript(proof, univariate, vk, &transcript, pairing_points, stage)?;
+            Ok(public_input_values)
+        }
 
-            // Update transcript with public input delta
-            let mut transcript = transcript;
-            transcript.relation_parameters.public_inputs_delta = public_input_delta;
+        /// A recursive proof's pairing-point accumulator, read from the last
+        /// `PAIRING_POINTS_SIZE` public inputs when `vk` says they carry one
+        /// (see `transcript::split_pairing_point_inputs` for the layout)
+        fn pairing_point_inputs(
+            vk: &VerificationKey,
+            public_inputs: &[Fr],
+        ) -> VerifierResult<Option<(G1Point, G1Point)>> {
+            if !vk.has_pairing_points {
+                return Ok(None);
+            }
+            let Some(user_count) = public_inputs.len().checked_sub(PAIRING_POINTS_SIZE) else {
+                return Err(VerifierError::InvalidPublicInputsLength {
+                    expected: PAIRING_POINTS_SIZE as u32,
+                    got: public_inputs.len() as u32,
+                });
+            };
+            let limbs = public_inputs[user_count..].try_into().expect("PAIRING_POINTS_SIZE limbs");
+            pairing_points_to_g1(limbs, user_count).map(Some)
+        }
 
+        /// Sumcheck, Shplemini and the pairing check against an already derived
+        /// transcript, folding in a recursive proof's `pairing_points` if given
+        fn verify_from_transcript(
+            &self,
+            proof: &ProofOpening,
+            univariate: impl Fn(usize) -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH],
+            vk: &VerificationKey,
+            transcript: &Transcript,
+            pairing_points: Option<(G1Point, G1Point)>,
+            stage: &mut Option<VerifyStage>,
+        ) -> VerifierResult<()> {
             // Verify sumcheck
-            self.verify_sumcheck(&parsed_proof, &transcript, &vk)?;
+            self.verify_sumcheck(proof, univariate, transcript, vk)?;
+            *stage = Some(VerifyStage::SumcheckPassed);
+            debug_event!(stage = ?stage, "verification stage passed");
 
             // Verify Shplemini (batched opening proof)
-            self.verify_shplemini(&parsed_proof, &vk, &transcript)?;
+            let (mut p_0, mut p_1) = self.verify_shplemini(proof, vk, transcript)?;
+            if let Some((lhs, rhs)) = pairing_points {
+                // The inner proof's pairing must hold too, so batch both into one check
+                let separator = recursion_separator(&lhs, &rhs, &p_0, &p_1);
+                p_0 = self.ec_add(&self.ec_mul(&p_0, separator), &lhs);
+                p_1 = self.ec_add(&self.ec_mul(&p_1, separator), &rhs);
+            }
+            *stage = Some(VerifyStage::ShpleminiPassed);
+            debug_event!(stage = ?stage, "verification stage passed");
 
-            Ok(true)
+            if !self.pairing_check(&p_0, &p_1) {
+                return Err(VerifierError::PairingCheckFailed);
+            }
+            *stage = Some(VerifyStage::PairingPassed);
+            debug_event!(stage = ?stage, "verification stage passed");
+
+            Ok(())
+        }
+
+        /// Every commitment in the proof must decode to a point on the curve,
+        /// and the wire commitments `w1..w4` must not be the point at infinity.
+        ///
+        /// Infinity stays allowed for the lookup commitments (zero for circuits
+        /// without lookups) and for the padded Gemini folds past `log_n`;
+        /// `shplonk_q`/`kzg_quotient` are rejected separately in `verify_shplemini`.
+        fn validate_proof_points(&self, proof: &ProofOpening) -> VerifierResult<()> {
+            let commitments = [
+                proof.w1,
+                proof.w2,
+                proof.w3,
+                proof.w4,
+                proof.z_perm,
+                proof.lookup_read_counts,
+                proof.lookup_read_tags,
+                proof.lookup_inverses,
+                proof.shplonk_q,
+                proof.kzg_quotient,
+            ];
+            for point in commitments.iter().chain(proof.gemini_fold_comms.iter()) {
+                if !is_on_curve(&self.convert_proof_point(point)?) {
+                    return Err(VerifierError::InvalidProofFormat);
+                }
+            }
+
+            for wire in [proof.w1, proof.w2, proof.w3, proof.w4] {
+                if is_infinity(&self.convert_proof_point(&wire)?) {
+                    return Err(VerifierError::InvalidProofFormat);
+                }
+            }
+
+            Ok(())
         }
 
         /// Validate public inputs format and size
@@ -815,28 +608,14 @@ mod verifier {
             &self,
             public_inputs: &[Vec<u8>],
             vk: &VerificationKey,
-        ) -> VerifierResult<()> {
-            let expected = vk.public_inputs_size.as_u32() as usize;
-            let got = public_inputs.len();
-            
-            if got != expected {
-                return Err(VerifierError::InvalidPublicInputsLength);
-            }
-            
+        ) -> VerifierResult<Vec<Fr>> {
+            vk.check_public_inputs_len(public_inputs.len())?;
+
             // Validate each input is 32 bytes
-            for input in public_inputs.iter() {
-                if input.len() != 32 {
-                    return Err(VerifierError::InvalidPublicInputFormat);
-                }
-                
-                // Validate input is a valid field element (< MODULUS)
-                let value = from_bytes_be(&input[..32].try_into().unwrap());
-                if value >= MODULUS {
-                    return Err(VerifierError::InvalidFieldElement);
-                }
-            }
-            
-            Ok(())
+            check_public_input_lengths(public_inputs)?;
+
+            // Validate each input is a valid field element (< MODULUS)
+            public_inputs.iter().map(|input| input.as_slice().decode_fr()).collect()
         }
 
         // #################################################################
@@ -865,10 +644,6 @@ mod verifier {
             })
         }
 
-        /// Converts a field element (32 bytes) from the VK array to Fr
-        fn vk_field_to_fr(&self, field_bytes: &[u8; 32]) -> Fr {
-            from_bytes_be(field_bytes)
-        }
         /// Calls the SHA256 precompile at 0x02
         fn sha256_precompile(&self, input: Vec<u8>) -> [u8; 32] {
             let result = build_call::<DefaultEnvironment>()
@@ -884,64 +659,30 @@ mod verifier {
         }
 
         /// Calls the Bn128Add precompile at 0x06
-        /// Input is two G1 points (64 + 64 = 128 bytes)
-        fn ec_add_precompile(&self, input: Vec<u8>) -> [u8; 64] {
-            let result = build_call::<DefaultEnvironment>()
-                .call(BN128_ADD_ADDR)
-                .exec_input(ExecutionInput::new(Selector::from([0; 4])).push_arg(&input))
-                .returns::<Vec<u8>>()
-                .try_invoke();
-            match result {
-                Ok(Ok(result_vec)) => {
-                    let mut arr = [0u8; 64];
-                    if result_vec.len() >= 64 {
-                        arr.copy_from_slice(&result_vec[..64]);
-                    }
-                    arr
-                }
-                _ => [0u8; 64],
-            }
+        fn ec_add(&self, a: &G1Point, b: &G1Point) -> G1Point {
+            let (x, y) = ink::env::bn128_add(
+                ink::U256(a.x.0),
+                ink::U256(a.y.0),
+                ink::U256(b.x.0),
+                ink::U256(b.y.0),
+            );
+            G1Point { x: U256(x.0), y: U256(y.0) }
         }
 
         /// Calls the Bn128Mul precompile at 0x07
-        /// Input is one G1 point and one scalar (64 + 32 = 96 bytes)
-        fn ec_mul_precompile(&self, input: Vec<u8>) -> [u8; 64] {
-            // TODO
-            let result = build_call::<DefaultEnvironment>()
-                .call(BN128_MUL_ADDR)
-                .exec_input(ExecutionInput::new(Selector::from([0; 4])).push_arg(&input))
-                .returns::<Vec<u8>>()
-                .try_invoke();
-            match result {
-                Ok(Ok(result_vec)) => {
-                    let mut arr = [0u8; 64];
-                    if result_vec.len() >= 64 {
-                        arr.copy_from_slice(&result_vec[..64]);
-                    }
-                    arr
-                }
-                _ => [0u8; 64],
-            }
+        fn ec_mul(&self, point: &G1Point, scalar: Fr) -> G1Point {
+            let (x, y) = ink::env::bn128_mul(
+                ink::U256(point.x.0),
+                ink::U256(point.y.0),
+                ink::U256(scalar.0),
+            );
+            G1Point { x: U256(x.0), y: U256(y.0) }
         }
 
         /// Calls the Bn128Pairing precompile at 0x08
         /// Input is a list of (G1, G2) pairs. (64 + 128) * N bytes.
-        fn ec_pairing_precompile(&self, input: Vec<u8>) -> bool {
-            let result = build_call::<DefaultEnvironment>()
-                .call(BN128_PAIRING_ADDR)
-                .exec_input(ExecutionInput::new(Selector::from([0; 4])).push_arg(&input))
-                .returns::<Vec<u8>>()
-                .try_invoke();
-            match result {
-                Ok(Ok(result_vec)) => {
-                    if result_vec.len() == 32 {
-                        result_vec[31] == 1
-                    } else {
-                        false
-                    }
-                }
-                _ => false,
-            }
+        fn ec_pairing(&self, input: &[u8]) -> bool {
+            ink::env::bn128_pairing(input)
         }
 
         /// Parses UltraHonk proof bytes into Proof structure
@@ -955,17 +696,7 @@ mod verifier {
         /// - kzg_quotient: 1 G1ProofPoint * 128 bytes = 128 bytes
         /// Total: ~14080 bytes
         fn parse_proof(&self, proof_bytes: &[u8]) -> Option<Proof> {
-            // Minimum expected size: ~14080 bytes
-            // 8 G1ProofPoints
-            const MIN_PROOF_SIZE: usize = 8 * 128 + 
-                28 * 8 * 32 + 
-                40 * 32 + 
-                27 * 128 + 
-                28 * 32 + 
-                128 + 
-                128;
-            
-            if proof_bytes.len() < MIN_PROOF_SIZE {
+            if proof_bytes.len() < PROOF_SIZE {
                 return None;
             }
 
@@ -976,9 +707,9 @@ mod verifier {
                 if *offset + 32 > proof_bytes.len() {
                     return None;
                 }
-                let bytes: [u8; 32] = proof_bytes[*offset..*offset + 32].try_into().ok()?;
+                let value = proof_bytes[*offset..*offset + 32].decode_fr().ok()?;
                 *offset += 32;
-                Some(from_bytes_be(&bytes))
+                Some(value)
             };
 
             // Helper to read G1ProofPoint (128 bytes: x_0, x_1, y_0, y_1)
@@ -1038,142 +769,128 @@ mod verifier {
             let kzg_quotient = read_g1_proof_point(&mut offset)?;
 
             Some(Proof {
-                w1,
-                w2,
-                w3,
-                w4,
-                z_perm,
-                lookup_read_counts,
-                lookup_read_tags,
-                lookup_inverses,
+                opening: ProofOpening {
+                    w1,
+                    w2,
+                    w3,
+                    w4,
+                    z_perm,
+                    lookup_read_counts,
+                    lookup_read_tags,
+                    lookup_inverses,
+                    sumcheck_evaluations,
+                    gemini_fold_comms,
+                    gemini_a_evaluations,
+                    shplonk_q,
+                    kzg_quotient,
+                },
                 sumcheck_univariates,
-                sumcheck_evaluations,
-                gemini_fold_comms,
-                gemini_a_evaluations,
-                shplonk_q,
-                kzg_quotient,
             })
         }
 
-        fn compute_public_input_delta(
-            &self,
-            public_inputs: &[Vec<u8>],
-            beta: Fr,
-            gamma: Fr,
-            n: Fr,
-        ) -> VerifierResult<Fr> {
-            let mut numerator = U256::one();
-            let mut denominator = U256::one();
-
-            let offset = U256::one();
-            let mut numerator_acc = add_mod(gamma, mul_mod(beta, add_mod(n, offset)));
-            let mut denominator_acc = sub_mod(gamma, mul_mod(beta, add_mod(offset, U256::one())));
-
-            for input in public_inputs {
-                let pub_input = from_bytes_be(&input[..32].try_into().unwrap());
-
-                numerator = mul_mod(numerator, add_mod(numerator_acc, pub_input));
-                denominator = mul_mod(denominator, add_mod(denominator_acc, pub_input));
-
-                numerator_acc = add_mod(numerator_acc, beta);
-                denominator_acc = sub_mod(denominator_acc, beta);
-            }
-
-            if denominator.is_zero() {
-                return Err(VerifierError::DivisionByZero);
-            }
-
-            Ok(div_mod(numerator, denominator))
-        }
-
         // ===================================================================
         // SUMCHECK VERIFICATION
         // ===================================================================
 
         fn verify_sumcheck(
             &self,
-            proof: &Proof,
+            proof: &ProofOpening,
+            univariate: impl Fn(usize) -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH],
             transcript: &Transcript,
             vk: &VerificationKey,
         ) -> VerifierResult<()> {
             let mut round_target = U256::zero();
             let mut pow_partial_eval = U256::one();
             
-            let log_n = vk.log_circuit_size.as_u32() as usize;
+            let log_n = vk.log_n()?;
             
             // Perform sumcheck over log_n rounds
             for round in 0..log_n {
-                let round_univariate = &proof.sumcheck_univariates[round];
+                let round_univariate = univariate(round);
                 
                 // Check that univariate(0) + univariate(1) == round_target
-                let sum = add_mod(round_univariate[0], round_univariate[1]);
-                if sum != round_target {
-                    return Err(VerifierError::SumcheckFailed);
-                }
-                
-                let round_challenge = transcript.sumcheck_u_challenges[round];
-                
-                // Compute next round target
-                round_target = self.compute_next_target_sum(round_univariate, round_challenge);
+                crate::sumcheck::check_round_sum(&round_univariate, round_target)?;
                 
-                // Update POW partial evaluation
-                pow_partial_eval = self.partially_evaluate_pow(
-                    transcript.gate_challenges[round],
-                    pow_partial_eval,
-                    round_challenge,
-                );
+                (round_target, pow_partial_eval) =
+                    self.fold_sumcheck_round(&round_univariate, transcript, round, pow_partial_eval)?;
+                debug_event!(round, challenge = ?transcript.sumcheck_u_challenges[round], "sumcheck round passed");
             }
             
             // Final check: evaluate grand honk relation
+            self.check_final_relation(&proof.sumcheck_evaluations, transcript, round_target, pow_partial_eval)?;
+            debug_event!(pow_partial_eval = ?pow_partial_eval, "sumcheck relation check passed");
+
+            Ok(())
+        }
+
+        /// Check only the final sumcheck claim: fold every round univariate at its
+        /// challenge and compare the result to the batched relations evaluated on
+        /// `proof.sumcheck_evaluations`, scaled by the final pow partial evaluation.
+        /// Unlike `verify_sumcheck` this skips the per-round `p(0) + p(1)` checks.
+        pub fn check_sumcheck_consistency(
+            &self,
+            proof: &Proof,
+            transcript: &Transcript,
+            vk: &VerificationKey,
+        ) -> VerifierResult<()> {
+            let mut target = U256::zero();
+            let mut pow_partial_eval = U256::one();
+
+            for round in 0..vk.log_n()? {
+                (target, pow_partial_eval) = self.fold_sumcheck_round(
+                    &proof.sumcheck_univariates[round],
+                    transcript,
+                    round,
+                    pow_partial_eval,
+                )?;
+            }
+
+            self.check_final_relation(&proof.opening.sumcheck_evaluations, transcript, target, pow_partial_eval)
+        }
+
+        /// Next round target and pow partial evaluation after `round`
+        fn fold_sumcheck_round(
+            &self,
+            univariate: &[Fr; BATCHED_RELATION_PARTIAL_LENGTH],
+            transcript: &Transcript,
+            round: usize,
+            pow_partial_eval: Fr,
+        ) -> VerifierResult<(Fr, Fr)> {
+            let round_challenge = transcript.sumcheck_u_challenges[round];
+            let target =
+                self.compute_next_target_sum(univariate, round_challenge)?;
+            let pow_partial_eval = self.partially_evaluate_pow(
+                transcript.gate_challenges[round],
+                pow_partial_eval,
+                round_challenge,
+            );
+            Ok((target, pow_partial_eval))
+        }
+
+        /// `final_target == batched_relations * pow_partial_eval`
+        fn check_final_relation(
+            &self,
+            evaluations: &[Fr; NUMBER_OF_ENTITIES],
+            transcript: &Transcript,
+            final_target: Fr,
+            pow_partial_eval: Fr,
+        ) -> VerifierResult<()> {
             let grand_honk_sum = crate::relations::accumulate_relation_evaluations(
-                &proof.sumcheck_evaluations,
+                evaluations,
                 &transcript.relation_parameters,
                 &transcript.alphas,
                 pow_partial_eval,
             );
-            
-            if grand_honk_sum != round_target {
+
+            if grand_honk_sum != final_target {
                 return Err(VerifierError::SumcheckEvaluationMismatch);
             }
-
             Ok(())
         }
 
-        fn compute_next_target_sum(&self, univariate: &[Fr; BATCHED_RELATION_PARTIAL_LENGTH], challenge: Fr) -> Fr {
-            // Barycentric Lagrange denominators
-            let denominators: [Fr; 8] = [
-                U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808492881").unwrap(),
-                U256::from_dec_str("720").unwrap(),
-                U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808491985").unwrap(),
-                U256::from_dec_str("144").unwrap(),
-                U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808492209").unwrap(),
-                U256::from_dec_str("240").unwrap(),
-                U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808489521").unwrap(),
-                U256::from_dec_str("5040").unwrap(),
-            ];
-            
-            // Compute B(x) = product of (x - i)
-            let mut numerator = U256::one();
-            for i in 0..8 {
-                numerator = mul_mod(numerator, sub_mod(challenge, U256::from(i)));
-            }
-            
-            // Compute denominator inverses
-            let mut denom_inverses = [U256::zero(); 8];
-            for i in 0..8 {
-                let mut denom = denominators[i];
-                denom = mul_mod(denom, sub_mod(challenge, U256::from(i)));
-                denom_inverses[i] = crate::field::inv_mod(denom);
-            }
-            
-            // Compute sum
-            let mut sum = U256::zero();
-            for i in 0..8 {
-                let term = mul_mod(univariate[i], denom_inverses[i]);
-                sum = add_mod(sum, term);
-            }
-            
-            mul_mod(sum, numerator)
+        /// Round univariate evaluated at `challenge`: the next round's target
+        fn compute_next_target_sum(&self, univariate: &[Fr; BATCHED_RELATION_PARTIAL_LENGTH], challenge: Fr) -> VerifierResult<Fr> {
+            crate::sumcheck::evaluate_univariate(univariate, challenge)
         }
 
         fn partially_evaluate_pow(&self, gate_challenge: Fr, current_eval: Fr, round_challenge: Fr) -> Fr {
@@ -1184,22 +901,807 @@ mod verifier {
             mul_mod(current_eval, term)
         }
 
+        /// Shplemini: batch every opening claim (Gemini folds + Shplonk) into a
+        /// single KZG check, returning the `(P_0, -W)` pair for `pairing_check`.
+        ///
+        /// With `W = kzg_quotient` and
+        ///     P_0 = [Q] + sum_i s_i * [C_i] + const_term * [1]_1 + z * [W]
+        /// where `[Q] = shplonk_q`, `[C_i]` are the VK/witness commitments and Gemini
+        /// fold commitments with their batching scalars `s_i`, the proof is valid iff
+        ///     e(P_0, [1]_2) * e(-W, [x]_2) == 1
         fn verify_shplemini(
             &self,
-            _proof: &Proof,
-            _vk: &VerificationKey,
-            _transcript: &Transcript,
-        ) -> VerifierResult<()> {
-            // TODO: implement full Gemini + Shplonk + KZG verification
-            
-            // Full implementation requires:
-            // 1. Computing r^{2^i} for i = 0..log_n
-            // 2. Batching all commitments and evaluations
-            // 3. Computing Gemini fold evaluations
-            // 4. Final pairing check
-            
-            // Placeholder to allow testing other components
+            proof: &ProofOpening,
+            vk: &VerificationKey,
+            transcript: &Transcript,
+        ) -> VerifierResult<(G1Point, G1Point)> {
+            let shplonk_q = self.convert_proof_point(&proof.shplonk_q)?;
+            let quotient_commitment = self.convert_proof_point(&proof.kzg_quotient)?;
+
+            // Neither opening quotient is the identity for an honest prover, and an
+            // infinity W would drop the [x]_2 pairing term entirely
+            if is_infinity(&shplonk_q) || is_infinity(&quotient_commitment) {
+                return Err(VerifierError::ShpleminiFailed);
+            }
+
+            let log_n = vk.log_n()?;
+            let invert = |value: Fr| try_inv_mod(value).ok_or(VerifierError::DivisionByZero);
+
+            // (r, r^2, r^4, ..., r^{2^{CONST_PROOF_SIZE_LOG_N - 1}})
+            let mut powers_of_r = [U256::zero(); CONST_PROOF_SIZE_LOG_N];
+            powers_of_r[0] = transcript.gemini_r;
+            for i in 1..CONST_PROOF_SIZE_LOG_N {
+                powers_of_r[i] = sqr_mod(powers_of_r[i - 1]);
+            }
+
+            let mut scalars = [U256::zero(); NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 2];
+            let mut commitments = [G1Point::default(); NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 2];
+
+            let mut pos_inverted_denominator = invert(sub_mod(transcript.shplonk_z, powers_of_r[0]))?;
+            let mut neg_inverted_denominator = invert(add_mod(transcript.shplonk_z, powers_of_r[0]))?;
+
+            let unshifted_scalar = add_mod(
+                pos_inverted_denominator,
+                mul_mod(transcript.shplonk_nu, neg_inverted_denominator),
+            );
+            let shifted_scalar = mul_mod(
+                invert(transcript.gemini_r)?,
+                sub_mod(
+                    pos_inverted_denominator,
+                    mul_mod(transcript.shplonk_nu, neg_inverted_denominator),
+                ),
+            );
+
+            scalars[0] = U256::one();
+            commitments[0] = shplonk_q;
+
+            // Batch the sumcheck evaluations with powers of rho; unshifted
+            // commitments are opened at r, shifted ones at -r
+            let mut batching_challenge = U256::one();
+            let mut batched_evaluation = U256::zero();
+            for (i, evaluation) in proof.sumcheck_evaluations.iter().enumerate() {
+                let scalar = if i < NUMBER_UNSHIFTED { unshifted_scalar } else { shifted_scalar };
+                scalars[1 + i] = mul_mod(neg_mod(scalar), batching_challenge);
+                batched_evaluation = add_mod(batched_evaluation, mul_mod(*evaluation, batching_challenge));
+                batching_challenge = mul_mod(batching_challenge, transcript.rho);
+            }
+
+            // Same entity order as the Solidity verifier's sumcheck evaluations
+            let vk_commitments = [
+                vk.qm, vk.qc, vk.ql, vk.qr, vk.qo, vk.q4, vk.q_lookup, vk.q_arith,
+                vk.q_delta_range, vk.q_elliptic, vk.q_aux, vk.q_poseidon2_external,
+                vk.q_poseidon2_internal, vk.s1, vk.s2, vk.s3, vk.s4, vk.id1, vk.id2,
+                vk.id3, vk.id4, vk.t1, vk.t2, vk.t3, vk.t4, vk.lagrange_first,
+                vk.lagrange_last,
+            ];
+            let witness_commitments = [
+                proof.w1, proof.w2, proof.w3, proof.w4, proof.z_perm,
+                proof.lookup_inverses, proof.lookup_read_counts, proof.lookup_read_tags,
+                // To be shifted
+                proof.w1, proof.w2, proof.w3, proof.w4, proof.z_perm,
+            ];
+            for (i, commitment) in vk_commitments.iter().enumerate() {
+                commitments[1 + i] = *commitment;
+            }
+            for (i, commitment) in witness_commitments.iter().enumerate() {
+                commitments[1 + vk_commitments.len() + i] = self.convert_proof_point(commitment)?;
+            }
+
+            // A_l(r^{2^l}) for l = 0..log_n
+            let fold_pos_evaluations = self.compute_fold_pos_evaluations(
+                &transcript.sumcheck_u_challenges,
+                batched_evaluation,
+                &proof.gemini_a_evaluations,
+                &powers_of_r,
+                log_n,
+            )?;
+
+            // Shplonk constant term contributions from A_0(+-r)
+            let mut constant_term_accumulator = mul_mod(fold_pos_evaluations[0], pos_inverted_denominator);
+            constant_term_accumulator = add_mod(
+                constant_term_accumulator,
+                mul_mod(
+                    mul_mod(proof.gemini_a_evaluations[0], transcript.shplonk_nu),
+                    neg_inverted_denominator,
+                ),
+            );
+            batching_challenge = sqr_mod(transcript.shplonk_nu);
+
+            // Contributions from A_l(+-r^{2^l}) for l = 1..log_n, and the scalar for
+            // each fold commitment. Rounds past log_n are padding and contribute nothing.
+            for i in 0..CONST_PROOF_SIZE_LOG_N - 1 {
+                let dummy_round = i + 1 >= log_n;
+
+                if !dummy_round {
+                    pos_inverted_denominator = invert(sub_mod(transcript.shplonk_z, powers_of_r[i + 1]))?;
+                    neg_inverted_denominator = invert(add_mod(transcript.shplonk_z, powers_of_r[i + 1]))?;
+
+                    let scaling_factor_pos = mul_mod(batching_challenge, pos_inverted_denominator);
+                    let scaling_factor_neg = mul_mod(
+                        mul_mod(batching_challenge, transcript.shplonk_nu),
+                        neg_inverted_denominator,
+                    );
+                    // [A_l] is scaled by -v^{2l}/(z - r^{2^l}) - v^{2l+1}/(z + r^{2^l})
+                    scalars[NUMBER_OF_ENTITIES + 1 + i] =
+                        add_mod(neg_mod(scaling_factor_neg), neg_mod(scaling_factor_pos));
+
+                    let contribution = add_mod(
+                        mul_mod(scaling_factor_neg, proof.gemini_a_evaluations[i + 1]),
+                        mul_mod(scaling_factor_pos, fold_pos_evaluations[i + 1]),
+                    );
+                    constant_term_accumulator = add_mod(constant_term_accumulator, contribution);
+                    batching_challenge = mul_mod(
+                        batching_challenge,
+                        sqr_mod(transcript.shplonk_nu),
+                    );
+                }
+
+                commitments[NUMBER_OF_ENTITIES + 1 + i] =
+                    self.convert_proof_point(&proof.gemini_fold_comms[i])?;
+            }
+
+            // Evaluation claims enter as const_term * [1]_1
+            commitments[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N] = G1Point {
+                x: U256::one(),
+                y: U256::from(2),
+            };
+            scalars[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N] = constant_term_accumulator;
+
+            // z * [W] moves the opening point into P_0
+            commitments[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 1] = quotient_commitment;
+            scalars[NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N + 1] = transcript.shplonk_z;
+
+            let p_0 = self.batch_mul(&commitments, &scalars);
+            let p_1 = negate(&quotient_commitment);
+            debug_event!(
+                gemini_r = ?transcript.gemini_r,
+                shplonk_z = ?transcript.shplonk_z,
+                "shplemini reduced to pairing points"
+            );
+
+            Ok((p_0, p_1))
+        }
+
+        /// Recover A_l(r^{2^l}) from the claimed A_l(-r^{2^l}), folding down from
+        /// the last round. Padding rounds past `log_n` leave the accumulator unchanged.
+        fn compute_fold_pos_evaluations(
+            &self,
+            sumcheck_u_challenges: &[Fr; CONST_PROOF_SIZE_LOG_N],
+            mut batched_eval_accumulator: Fr,
+            gemini_evaluations: &[Fr; CONST_PROOF_SIZE_LOG_N],
+            powers_of_r: &[Fr; CONST_PROOF_SIZE_LOG_N],
+            log_n: usize,
+        ) -> VerifierResult<[Fr; CONST_PROOF_SIZE_LOG_N]> {
+            let mut fold_pos_evaluations = [U256::zero(); CONST_PROOF_SIZE_LOG_N];
+
+            for i in (1..=CONST_PROOF_SIZE_LOG_N).rev() {
+                let challenge_power = powers_of_r[i - 1];
+                let u = sumcheck_u_challenges[i - 1];
+                let one_minus_u = sub_mod(U256::one(), u);
+
+                let mut round_acc = sub_mod(
+                    mul_mod(mul_mod(challenge_power, batched_eval_accumulator), U256::from(2)),
+                    mul_mod(
+                        gemini_evaluations[i - 1],
+                        sub_mod(mul_mod(challenge_power, one_minus_u), u),
+                    ),
+                );
+                let denominator = add_mod(mul_mod(challenge_power, one_minus_u), u);
+                round_acc = mul_mod(
+                    round_acc,
+                    try_inv_mod(denominator).ok_or(VerifierError::DivisionByZero)?,
+                );
+
+                if i <= log_n {
+                    batched_eval_accumulator = round_acc;
+                    fold_pos_evaluations[i - 1] = round_acc;
+                }
+            }
+
+            Ok(fold_pos_evaluations)
+        }
+
+        /// Multi-scalar multiplication via the Bn128Mul / Bn128Add precompiles
+        fn batch_mul(&self, points: &[G1Point], scalars: &[Fr]) -> G1Point {
+            let mut acc = G1Point::default();
+            for (point, scalar) in points.iter().zip(scalars.iter()) {
+                let term = self.ec_mul(point, *scalar);
+                acc = self.ec_add(&acc, &term);
+            }
+            acc
+        }
+
+        /// e(rhs, [1]_2) * e(lhs, [x]_2) == 1
+        fn pairing_check(&self, rhs: &G1Point, lhs: &G1Point) -> bool {
+            let mut input = Vec::with_capacity(2 * (64 + 128));
+            input.extend_from_slice(&self.g1_point_to_bytes(rhs));
+            input.extend_from_slice(&G2_GEN.to_bytes());
+            input.extend_from_slice(&self.g1_point_to_bytes(lhs));
+            input.extend_from_slice(&G2_X.to_bytes());
+            let ok = self.ec_pairing(&input);
+            debug_event!(ok, "pairing check");
+            ok
+        }
+
+        /// Recombine a proof point's limbs: each coordinate is `lo | (hi << 136)`
+        fn convert_proof_point(&self, point: &G1ProofPoint) -> VerifierResult<G1Point> {
+            point.to_g1()
+        }
+    }
+
+    /// Proof bytes fed in pieces, as they arrive over a size-limited transport,
+    /// then verified by `finish` with the same checks and errors as `verify`.
+    /// `finish` reads each sumcheck round's univariate from the buffer as it
+    /// is needed instead of decoding the whole proof first.
+    ///
+    /// The bytes are buffered rather than hashed as they come: round 0 of the
+    /// transcript absorbs the public inputs ahead of `w1`, and those are only
+    /// known at `finish`. The buffer stops at `PROOF_SIZE`, and every field is
+    /// range checked as soon as its last byte arrives, so a bad proof fails at
+    /// the chunk that carries the bad field. The session borrows the verifier,
+    /// so it lives within one call; spreading a proof over several contract
+    /// calls means keeping the bytes in storage between them. It verifies
+    /// against the VK the contract had when the session began.
+    pub struct VerifySession<'a> {
+        verifier: &'a Verifier,
+        vk: VerificationKey,
+        bytes: Vec<u8>,
+    }
+
+    impl<'a> VerifySession<'a> {
+        /// `InvalidVerificationKey` if the contract has no VK set
+        pub fn new(verifier: &'a Verifier) -> VerifierResult<Self> {
+            Ok(VerifySession {
+                verifier,
+                vk: verifier.reconstruct_vk()?,
+                bytes: Vec::with_capacity(PROOF_SIZE),
+            })
+        }
+
+        /// Append the next bytes of the proof, in serialized order. Like
+        /// `verify`, anything past `PROOF_SIZE` is ignored. A chunk that
+        /// completes a non-canonical field is rejected whole, leaving the
+        /// session as it was.
+        pub fn absorb_chunk(&mut self, bytes: &[u8]) -> VerifierResult<()> {
+            let take = bytes.len().min(PROOF_SIZE - self.bytes.len());
+            // The buffered start of a field this chunk may complete
+            let mut pending = self.bytes[self.bytes.len() - self.bytes.len() % 32..].to_vec();
+            pending.extend_from_slice(&bytes[..take]);
+
+            if pending.chunks_exact(32).any(|field| field.decode_fr().is_err()) {
+                return Err(VerifierError::InvalidProofFormat);
+            }
+            self.bytes.extend_from_slice(&bytes[..take]);
             Ok(())
         }
+
+        /// Verify the absorbed proof; `InvalidProofFormat` if it is incomplete
+        pub fn finish(self, public_inputs: &[Vec<u8>]) -> VerifierResult<bool> {
+            if self.verifier.paused {
+                return Err(VerifierError::Paused);
+            }
+
+            let reader = ProofReader::new(&self.bytes)?;
+            self.verifier
+                .verify_opening(
+                    &self.vk,
+                    &reader.opening(),
+                    |round| reader.univariate(round),
+                    public_inputs,
+                    &mut None,
+                )
+                .map(|_| true)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Keccak-256 of `VK_BYTES`, computed by the generator
+        const VK_HASH: [u8; 32] = *b"\x98\xa0\xa1\x38\x6f\x99\xd9\x7e\x54\x35\xe8\x43\x02\x63\x30\x99\xe5\xa2\x14\x4b\xf8\x68\xcb\x1a\x97\x9b\x8e\xef\xe3\xdd\x77\x36";
+
+        #[ink::test]
+        fn circuit_info_matches_vk() {
+            let verifier = Verifier::new().unwrap();
+            let vk = verifier.reconstruct_vk().unwrap();
+            let (circuit_size, num_public_inputs, vk_hash) = verifier.circuit_info().unwrap();
+
+            assert_eq!(U256::from(circuit_size), vk.circuit_size);
+            assert_eq!(U256::from(num_public_inputs), vk.public_inputs_size);
+            assert_eq!(vk_hash, VK_HASH);
+        }
+
+        #[ink::test]
+        fn circuit_info_rejects_sizes_wider_than_u64() {
+            let mut verifier = Verifier::new().unwrap();
+            let mut vk = verifier.vk_fields().unwrap().to_vec();
+            // Field 1 is public_inputs_size; self_test does not bound it
+            vk[1] = to_bytes_be(U256::one() << 64);
+            verifier.stored_vk.set(&vk);
+            assert_eq!(verifier.circuit_info(), Err(VerifierError::InvalidVerificationKey));
+        }
+
+        #[ink::test]
+        fn zeroed_kzg_quotient_fails_shplemini() {
+            let verifier = Verifier::new().unwrap();
+            let vk = verifier.reconstruct_vk().unwrap();
+            let opening = ProofOpening {
+                // shplonk_q = [1]_1, kzg_quotient left at the all-zero (infinity) encoding
+                shplonk_q: G1ProofPoint {
+                    x_0: U256::one(),
+                    x_1: U256::zero(),
+                    y_0: U256::from(2),
+                    y_1: U256::zero(),
+                },
+                ..ProofOpening::default()
+            };
+            let proof = Proof { opening, ..Proof::default() };
+
+            let public_inputs = vec![vec![0u8; 32]; vk.public_inputs_size.as_usize()];
+            let transcript = Transcript::generate(&proof, &public_inputs, &vk, U256::one()).unwrap();
+            assert_eq!(
+                verifier.verify_shplemini(&proof.opening, &vk, &transcript),
+                Err(VerifierError::ShpleminiFailed)
+            );
+        }
+
+        /// A proof whose sumcheck is trivially satisfied (all-zero univariates and
+        /// evaluations) but whose KZG quotient is the point at infinity
+        fn sumcheck_only_proof() -> Vec<u8> {
+            let generator = G1ProofPoint {
+                x_0: U256::one(),
+                x_1: U256::zero(),
+                y_0: U256::from(2),
+                y_1: U256::zero(),
+            };
+            let opening = ProofOpening {
+                w1: generator,
+                w2: generator,
+                w3: generator,
+                w4: generator,
+                shplonk_q: generator,
+                ..ProofOpening::default()
+            };
+            proof_to_bytes(&Proof { opening, ..Proof::default() })
+        }
+
+        fn proof_to_bytes(proof: &Proof) -> Vec<u8> {
+            let opening = &proof.opening;
+            let mut bytes = Vec::new();
+            let push_point = |bytes: &mut Vec<u8>, p: &G1ProofPoint| {
+                for limb in [p.x_0, p.x_1, p.y_0, p.y_1] {
+                    bytes.extend_from_slice(&to_bytes_be(limb));
+                }
+            };
+            for p in [
+                &opening.w1,
+                &opening.w2,
+                &opening.w3,
+                &opening.w4,
+                &opening.z_perm,
+                &opening.lookup_read_counts,
+                &opening.lookup_read_tags,
+                &opening.lookup_inverses,
+            ] {
+                push_point(&mut bytes, p);
+            }
+            for round in proof.sumcheck_univariates.iter() {
+                for eval in round.iter() {
+                    bytes.extend_from_slice(&to_bytes_be(*eval));
+                }
+            }
+            for eval in opening.sumcheck_evaluations.iter() {
+                bytes.extend_from_slice(&to_bytes_be(*eval));
+            }
+            for p in opening.gemini_fold_comms.iter() {
+                push_point(&mut bytes, p);
+            }
+            for eval in opening.gemini_a_evaluations.iter() {
+                bytes.extend_from_slice(&to_bytes_be(*eval));
+            }
+            push_point(&mut bytes, &opening.shplonk_q);
+            push_point(&mut bytes, &opening.kzg_quotient);
+            bytes
+        }
+
+        fn public_inputs(verifier: &Verifier) -> Vec<Vec<u8>> {
+            let (_, num_public_inputs, _) = verifier.circuit_info().unwrap();
+            (0..num_public_inputs)
+                .map(|i| to_bytes_be(U256::from(i + 1)).to_vec())
+                .collect()
+        }
+
+        #[ink::test]
+        fn verify_staged_reports_sumcheck_passed() {
+            let verifier = Verifier::new().unwrap();
+            let (stage, result) =
+                verifier.verify_staged(sumcheck_only_proof(), public_inputs(&verifier));
+
+            assert_eq!(stage, Some(VerifyStage::SumcheckPassed));
+            assert_eq!(result, Err(VerifierError::ShpleminiFailed));
+        }
+
+        #[ink::test]
+        fn verify_staged_reports_nothing_for_unparseable_proof() {
+            let verifier = Verifier::new().unwrap();
+            let (stage, result) = verifier.verify_staged(vec![0u8; 32], public_inputs(&verifier));
+
+            assert_eq!(stage, None);
+            assert_eq!(result, Err(VerifierError::InvalidProofFormat));
+        }
+
+        #[ink::test]
+        fn pause_blocks_verify() {
+            let mut verifier = Verifier::new().unwrap();
+            let proof = sumcheck_only_proof();
+            let inputs = public_inputs(&verifier);
+
+            assert_eq!(verifier.set_paused(true), Ok(()));
+            assert!(verifier.is_paused());
+            assert_eq!(
+                verifier.verify_staged(proof.clone(), inputs.clone()),
+                (None, Err(VerifierError::Paused))
+            );
+
+            assert_eq!(verifier.set_paused(false), Ok(()));
+            assert_eq!(
+                verifier.verify_staged(proof, inputs).0,
+                Some(VerifyStage::SumcheckPassed)
+            );
+            assert_eq!(ink::env::test::recorded_events().len(), 2);
+        }
+
+        #[ink::test]
+        fn only_owner_can_pause() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut verifier = Verifier::new().unwrap();
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(verifier.set_paused(true), Err(VerifierError::NotOwner));
+            assert!(!verifier.is_paused());
+            assert!(ink::env::test::recorded_events().is_empty());
+        }
+
+        #[ink::test]
+        fn infinity_wire_commitment_is_rejected() {
+            let verifier = Verifier::new().unwrap();
+            let mut proof = verifier.parse_proof(&sumcheck_only_proof()).unwrap();
+            proof.opening.w1 = G1ProofPoint::default();
+
+            let (stage, result) =
+                verifier.verify_staged(proof_to_bytes(&proof), public_inputs(&verifier));
+
+            assert_eq!(stage, Some(VerifyStage::Parsed));
+            assert_eq!(result, Err(VerifierError::InvalidProofFormat));
+        }
+
+        #[ink::test]
+        fn vk_fields_split_from_raw_bytes() {
+            assert_eq!(VK.as_flattened(), VK_BYTES.as_slice());
+            assert!(Verifier::new().unwrap().reconstruct_vk().is_ok());
+        }
+
+        #[ink::test]
+        fn verify_before_set_vk_fails() {
+            let verifier = Verifier::new_empty();
+            let inputs = vec![vec![0u8; 32]; 1];
+
+            assert_eq!(
+                verifier.verify_staged(sumcheck_only_proof(), inputs),
+                (None, Err(VerifierError::InvalidVerificationKey))
+            );
+            assert_eq!(verifier.circuit_info(), Err(VerifierError::InvalidVerificationKey));
+        }
+
+        #[ink::test]
+        fn verify_after_set_vk_uses_new_key() {
+            let mut verifier = Verifier::new_empty();
+            assert_eq!(verifier.set_vk(VK_BYTES.to_vec()), Ok(()));
+            assert_eq!(verifier.circuit_info(), Verifier::new().unwrap().circuit_info());
+
+            let (stage, _) =
+                verifier.verify_staged(sumcheck_only_proof(), public_inputs(&verifier));
+            assert_eq!(stage, Some(VerifyStage::SumcheckPassed));
+        }
+
+        #[ink::test]
+        fn set_vk_rejects_non_owner_and_bad_length() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut verifier = Verifier::new_empty();
+
+            assert_eq!(
+                verifier.set_vk(VK_BYTES[..VK_BYTES.len() - 32].to_vec()),
+                Err(VerifierError::InvalidVerificationKey)
+            );
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(verifier.set_vk(VK_BYTES.to_vec()), Err(VerifierError::NotOwner));
+            assert!(verifier.reconstruct_vk().is_err());
+        }
+
+        #[ink::test]
+        fn set_vk_rejects_off_curve_commitment() {
+            let mut vk = VK_BYTES.to_vec();
+            // Field 20 is the lowest limb of qm.x
+            vk[20 * 32 + 31] ^= 1;
+            let mut verifier = Verifier::new_empty();
+
+            assert_eq!(verifier.set_vk(vk), Err(VerifierError::InvalidVerificationKey));
+            assert!(verifier.reconstruct_vk().is_err());
+        }
+
+        #[cfg(feature = "tracing")]
+        mod tracing_events {
+            use super::*;
+            use std::sync::{Arc, Mutex};
+            use tracing::field::{Field, Visit};
+            use tracing::span::{Attributes, Id, Record};
+            use tracing::{Event, Metadata, Subscriber};
+
+            /// Records the `message` of every event
+            #[derive(Clone, Default)]
+            struct Messages(Arc<Mutex<Vec<String>>>);
+
+            impl Visit for Messages {
+                fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+                    if field.name() == "message" {
+                        self.0.lock().unwrap().push(format!("{:?}", value));
+                    }
+                }
+            }
+
+            impl Subscriber for Messages {
+                fn enabled(&self, _: &Metadata<'_>) -> bool {
+                    true
+                }
+                fn new_span(&self, _: &Attributes<'_>) -> Id {
+                    Id::from_u64(1)
+                }
+                fn record(&self, _: &Id, _: &Record<'_>) {}
+                fn record_follows_from(&self, _: &Id, _: &Id) {}
+                fn event(&self, event: &Event<'_>) {
+                    event.record(&mut self.clone());
+                }
+                fn enter(&self, _: &Id) {}
+                fn exit(&self, _: &Id) {}
+            }
+
+            #[ink::test]
+            fn stage_events_fire() {
+                let verifier = Verifier::new().unwrap();
+                let messages = Messages::default();
+
+                // Off-chain runs stop before the pairing precompile, so the
+                // events up to the sumcheck are what can be observed here
+                let (stage, _) = tracing::subscriber::with_default(messages.clone(), || {
+                    verifier.verify_staged(sumcheck_only_proof(), public_inputs(&verifier))
+                });
+                assert_eq!(stage, Some(VerifyStage::SumcheckPassed));
+
+                let log_n = verifier.reconstruct_vk().unwrap().log_circuit_size.as_usize();
+                let messages = messages.0.lock().unwrap();
+                let count = |msg: &str| messages.iter().filter(|m| *m == msg).count();
+                assert_eq!(count("verification stage passed"), 4);
+                assert_eq!(count("sumcheck round passed"), log_n);
+                assert_eq!(count("sumcheck relation check passed"), 1);
+            }
+        }
+
+        #[ink::test]
+        fn sumcheck_consistency_detects_perturbed_evaluation() {
+            use crate::relations::Wire;
+
+            let verifier = Verifier::new().unwrap();
+            let vk = verifier.reconstruct_vk().unwrap();
+            let mut proof = verifier.parse_proof(&sumcheck_only_proof()).unwrap();
+            let transcript =
+                Transcript::generate(&proof, &public_inputs(&verifier), &vk, U256::one()).unwrap();
+
+            // All-zero univariates and evaluations satisfy every relation
+            assert_eq!(verifier.check_sumcheck_consistency(&proof, &transcript, &vk), Ok(()));
+
+            // q_arith * q_c = 1 leaves the arithmetic relation unsatisfied
+            proof.opening.sumcheck_evaluations[Wire::QArith as usize] = U256::one();
+            proof.opening.sumcheck_evaluations[Wire::QC as usize] = U256::one();
+            assert_eq!(
+                verifier.check_sumcheck_consistency(&proof, &transcript, &vk),
+                Err(VerifierError::SumcheckEvaluationMismatch)
+            );
+        }
+
+        #[ink::test]
+        fn set_vk_rejects_log_circuit_size_above_proof_capacity() {
+            let mut vk = VK_BYTES.to_vec();
+            // Field 0 is circuit_size, big-endian; log_circuit_size follows from it
+            vk[..32].copy_from_slice(&to_bytes_be(U256::one() << 30));
+            let mut verifier = Verifier::new_empty();
+            assert_eq!(verifier.set_vk(vk), Err(VerifierError::InvalidVerificationKey));
+            assert_eq!(verifier.reconstruct_vk().map(|_| ()), Err(VerifierError::InvalidVerificationKey));
+        }
+
+        #[ink::test]
+        fn verify_and_extract_fails_like_verify() {
+            let verifier = Verifier::new().unwrap();
+            let inputs = public_inputs(&verifier);
+
+            let (_, expected) = verifier.verify_staged(sumcheck_only_proof(), inputs.clone());
+            assert_eq!(
+                verifier.verify_and_extract(&sumcheck_only_proof(), &inputs),
+                Err(expected.unwrap_err())
+            );
+
+            let short = &inputs[..inputs.len() - 1];
+            assert_eq!(
+                verifier.verify_and_extract(&sumcheck_only_proof(), short),
+                Err(verifier.verify(sumcheck_only_proof(), short.to_vec()).unwrap_err())
+            );
+        }
+
+        #[ink::test]
+        fn extracted_public_inputs_match_input_bytes() {
+            // The pairing precompile is unavailable off-chain, so check the
+            // values `verify_and_extract` returns after its final stage
+            let verifier = Verifier::new().unwrap();
+            let vk = verifier.reconstruct_vk().unwrap();
+            let inputs = public_inputs(&verifier);
+
+            let values = verifier.validate_public_inputs(&inputs, &vk).unwrap();
+            let round_trip: Vec<Vec<u8>> = values.iter().map(|v| to_bytes_be(*v).to_vec()).collect();
+            assert_eq!(round_trip, inputs);
+        }
+
+        /// `VK_BYTES` with one user input plus the pairing-point limbs, flagged
+        /// as such if `has_pairing_points`
+        fn recursive_vk(has_pairing_points: bool) -> Vec<u8> {
+            let mut vk = VK_BYTES.to_vec();
+            let field = |i: usize| i * 32..(i + 1) * 32;
+            // Field 1 is public_inputs_size, field 3 the flag, then the limb indices
+            vk[field(1)].copy_from_slice(&to_bytes_be(U256::from(1 + PAIRING_POINTS_SIZE)));
+            if has_pairing_points {
+                vk[field(3)].copy_from_slice(&to_bytes_be(U256::one()));
+                for k in 0..PAIRING_POINTS_SIZE {
+                    vk[field(4 + k)].copy_from_slice(&to_bytes_be(U256::from(1 + k)));
+                }
+            }
+            vk
+        }
+
+        /// A verifier whose VK expects one user input plus the pairing-point
+        /// limbs, and matching inputs with lhs = [1]_1, rhs = -[1]_1
+        fn recursive_setup() -> (Verifier, Vec<Vec<u8>>) {
+            let mut verifier = Verifier::new_empty();
+            assert_eq!(verifier.set_vk(recursive_vk(true)), Ok(()));
+            assert!(verifier.reconstruct_vk().unwrap().has_pairing_points);
+
+            let limbs = |value: U256| -> [U256; 4] {
+                let mask = (U256::one() << 68) - U256::one();
+                core::array::from_fn(|k| (value >> (68 * k)) & mask)
+            };
+            let minus_two = crate::curve::Q - U256::from(2);
+            let mut inputs = vec![to_bytes_be(U256::from(7)).to_vec()];
+            for coordinate in [U256::one(), U256::from(2), U256::one(), minus_two] {
+                inputs.extend(limbs(coordinate).iter().map(|limb| to_bytes_be(*limb).to_vec()));
+            }
+            (verifier, inputs)
+        }
+
+        #[ink::test]
+        fn pairing_point_inputs_are_decoded_before_sumcheck() {
+            let (verifier, mut inputs) = recursive_setup();
+
+            // Valid accumulator: decoded, then on to the proof itself
+            assert!(verifier.verify_staged(sumcheck_only_proof(), inputs.clone()).0.is_some());
+
+            // An oversized limb only matters when the VK says the inputs are pairing points
+            inputs[1] = to_bytes_be(U256::one() << 68).to_vec();
+            let expected = Err(VerifierError::InvalidPublicInputFormat { index: 1 });
+            assert_eq!(verifier.verify(sumcheck_only_proof(), inputs.clone()), expected);
+            assert_eq!(
+                verifier.verify_and_extract(&sumcheck_only_proof(), &inputs),
+                Err(VerifierError::InvalidPublicInputFormat { index: 1 })
+            );
+
+            let mut unflagged = Verifier::new_empty();
+            assert_eq!(unflagged.set_vk(recursive_vk(false)), Ok(()));
+            assert_ne!(unflagged.verify(sumcheck_only_proof(), inputs), expected);
+        }
+
+        #[ink::test]
+        fn pairing_point_flag_is_checked_against_the_vk() {
+            let mut verifier = Verifier::new_empty();
+            let field = |i: usize| i * 32..(i + 1) * 32;
+
+            // The embedded VK has a single public input, no room for the limbs
+            let mut vk = VK_BYTES.to_vec();
+            vk[field(3)].copy_from_slice(&to_bytes_be(U256::one()));
+            assert_eq!(verifier.set_vk(vk), Err(VerifierError::InvalidVerificationKey));
+
+            // Limbs anywhere but the trailing inputs
+            let mut vk = recursive_vk(true);
+            vk[field(4)].copy_from_slice(&to_bytes_be(U256::zero()));
+            assert_eq!(verifier.set_vk(vk), Err(VerifierError::InvalidVerificationKey));
+
+            // Neither 0 nor 1
+            let mut vk = recursive_vk(true);
+            vk[field(3)].copy_from_slice(&to_bytes_be(U256::from(2)));
+            assert_eq!(verifier.set_vk(vk), Err(VerifierError::InvalidVerificationKey));
+        }
+
+        #[ink::test]
+        fn verify_with_generated_transcript_matches_verify() {
+            let verifier = Verifier::new().unwrap();
+            let vk = verifier.reconstruct_vk().unwrap();
+            let inputs = public_inputs(&verifier);
+            let proof = verifier.parse_proof(&sumcheck_only_proof()).unwrap();
+            let transcript = Transcript::generate(&proof, &inputs, &vk, U256::one()).unwrap();
+            let values = verifier.validate_public_inputs(&inputs, &vk).unwrap();
+
+            assert_eq!(
+                verifier.verify_with_transcript(&vk, &proof, &values, &transcript),
+                verifier.verify(sumcheck_only_proof(), inputs.clone())
+            );
+            assert_eq!(
+                verifier.verify_with_transcript(&vk, &proof, &values[1..], &transcript),
+                verifier.verify(sumcheck_only_proof(), inputs[1..].to_vec())
+            );
+
+            let mut zeroed = transcript;
+            zeroed.sumcheck_u_challenges[0] = U256::zero();
+            assert_eq!(
+                verifier.verify_with_transcript(&vk, &proof, &values, &zeroed),
+                Err(VerifierError::InvalidTranscript)
+            );
+        }
+
+        #[ink::test]
+        fn public_inputs_must_be_exactly_32_bytes() {
+            let (verifier, inputs) = recursive_setup();
+
+            let mut padded = inputs.clone();
+            padded[3].push(0);
+            let mut empty = inputs;
+            empty[16].clear();
+
+            for (inputs, index) in [(padded, 3), (empty, 16)] {
+                let expected = Err(VerifierError::InvalidPublicInputFormat { index });
+                assert_eq!(verifier.verify(sumcheck_only_proof(), inputs), expected);
+            }
+        }
+
+        #[ink::test]
+        fn chunked_session_matches_verify() {
+            let verifier = Verifier::new().unwrap();
+            let proof = sumcheck_only_proof();
+            let inputs = public_inputs(&verifier);
+
+            let mut session = VerifySession::new(&verifier).unwrap();
+            // Chunk borders that split fields, including one past PROOF_SIZE
+            let (first, rest) = proof.split_at(1000);
+            let (second, third) = rest.split_at(5001);
+            for chunk in [first, second, third, &[0xff; 40]] {
+                assert_eq!(session.absorb_chunk(chunk), Ok(()));
+            }
+            assert_eq!(session.finish(&inputs), verifier.verify(proof.clone(), inputs.clone()));
+
+            // A non-canonical field fails the chunk that completes it, and the
+            // session carries on as if that chunk never came
+            let mut session = VerifySession::new(&verifier).unwrap();
+            assert_eq!(session.absorb_chunk(&proof[..1000]), Ok(()));
+            assert_eq!(session.absorb_chunk(&[0xff; 56]), Err(VerifierError::InvalidProofFormat));
+            assert_eq!(session.absorb_chunk(&proof[1000..]), Ok(()));
+            assert_eq!(session.finish(&inputs), verifier.verify(proof.clone(), inputs.clone()));
+
+            // An incomplete proof cannot be finished
+            let mut session = VerifySession::new(&verifier).unwrap();
+            assert_eq!(session.absorb_chunk(&proof[..PROOF_SIZE - 32]), Ok(()));
+            assert_eq!(session.finish(&inputs), Err(VerifierError::InvalidProofFormat));
+
+            // Sessions check against the contract's VK, so it needs one
+            let empty = Verifier::new_empty();
+            assert!(matches!(VerifySession::new(&empty), Err(VerifierError::InvalidVerificationKey)));
+        }
     }
 }
@@ -0,0 +1,151 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Reads proof fields straight out of the serialized bytes, so a verifier can
+//! walk the sumcheck rounds one univariate at a time instead of decoding the
+//! whole `Proof` up front.
+
+use crate::constants::{
+    BATCHED_RELATION_PARTIAL_LENGTH, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ENTITIES,
+    NUMBER_OF_WITNESS_COMMITMENTS,
+};
+use crate::errors::{VerifierError, VerifierResult};
+use crate::field::{from_bytes_be, DecodeFr, Fr};
+use crate::honk_structs::G1ProofPoint;
+use crate::transcript::ProofOpening;
+
+const FIELD_SIZE: usize = 32;
+const POINT_SIZE: usize = 4 * FIELD_SIZE;
+const UNIVARIATE_SIZE: usize = BATCHED_RELATION_PARTIAL_LENGTH * FIELD_SIZE;
+
+const UNIVARIATES_OFFSET: usize = NUMBER_OF_WITNESS_COMMITMENTS * POINT_SIZE;
+const EVALUATIONS_OFFSET: usize = UNIVARIATES_OFFSET + CONST_PROOF_SIZE_LOG_N * UNIVARIATE_SIZE;
+
+/// Bytes in a serialized proof; anything past this is ignored
+pub const PROOF_SIZE: usize = EVALUATIONS_OFFSET
+    + NUMBER_OF_ENTITIES * FIELD_SIZE
+    + (CONST_PROOF_SIZE_LOG_N - 1) * POINT_SIZE
+    + CONST_PROOF_SIZE_LOG_N * FIELD_SIZE
+    + 2 * POINT_SIZE;
+
+/// A proof checked up front for length and canonical fields, read lazily after
+pub struct ProofReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ProofReader<'a> {
+    /// Rejects, like `parse_proof`, proofs shorter than `PROOF_SIZE` or with
+    /// any field `>= MODULUS`, so the getters below cannot fail
+    pub fn new(bytes: &'a [u8]) -> VerifierResult<Self> {
+        if bytes.len() < PROOF_SIZE {
+            return Err(VerifierError::InvalidProofFormat);
+        }
+        let bytes = &bytes[..PROOF_SIZE];
+        if bytes.chunks_exact(FIELD_SIZE).any(|field| field.decode_fr().is_err()) {
+            return Err(VerifierError::InvalidProofFormat);
+        }
+        Ok(ProofReader { bytes })
+    }
+
+    /// Sumcheck univariate sent in `round`
+    pub fn univariate(&self, round: usize) -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH] {
+        let mut offset = UNIVARIATES_OFFSET + round * UNIVARIATE_SIZE;
+        core::array::from_fn(|_| self.read_fr(&mut offset))
+    }
+
+    /// Every proof field except the sumcheck univariates
+    pub fn opening(&self) -> ProofOpening {
+        let mut offset = 0;
+        let w1 = self.read_point(&mut offset);
+        let w2 = self.read_point(&mut offset);
+        let w3 = self.read_point(&mut offset);
+        let w4 = self.read_point(&mut offset);
+        let z_perm = self.read_point(&mut offset);
+        let lookup_read_counts = self.read_point(&mut offset);
+        let lookup_read_tags = self.read_point(&mut offset);
+        let lookup_inverses = self.read_point(&mut offset);
+
+        // The univariates sit in between; `univariate` reads them per round
+        offset = EVALUATIONS_OFFSET;
+        let sumcheck_evaluations = core::array::from_fn(|_| self.read_fr(&mut offset));
+        let gemini_fold_comms = core::array::from_fn(|_| self.read_point(&mut offset));
+        let gemini_a_evaluations = core::array::from_fn(|_| self.read_fr(&mut offset));
+        let shplonk_q = self.read_point(&mut offset);
+        let kzg_quotient = self.read_point(&mut offset);
+
+        ProofOpening {
+            w1,
+            w2,
+            w3,
+            w4,
+            z_perm,
+            lookup_read_counts,
+            lookup_read_tags,
+            lookup_inverses,
+            sumcheck_evaluations,
+            gemini_fold_comms,
+            gemini_a_evaluations,
+            shplonk_q,
+            kzg_quotient,
+        }
+    }
+
+    fn read_fr(&self, offset: &mut usize) -> Fr {
+        let field = self.bytes[*offset..*offset + FIELD_SIZE]
+            .try_into()
+            .expect("offsets stay inside PROOF_SIZE");
+        let value = from_bytes_be(field);
+        *offset += FIELD_SIZE;
+        value
+    }
+
+    fn read_point(&self, offset: &mut usize) -> G1ProofPoint {
+        G1ProofPoint {
+            x_0: self.read_fr(offset),
+            x_1: self.read_fr(offset),
+            y_0: self.read_fr(offset),
+            y_1: self.read_fr(offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{to_bytes_be, MODULUS};
+    use primitive_types::U256;
+
+    /// Field `i` of the proof holds the value `i`
+    fn counting_proof() -> Vec<u8> {
+        (0..PROOF_SIZE / FIELD_SIZE)
+            .flat_map(|i| to_bytes_be(U256::from(i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_reads_fields_at_their_offsets() {
+        let bytes = counting_proof();
+        let reader = ProofReader::new(&bytes).unwrap();
+
+        let first_univariate_field = UNIVARIATES_OFFSET / FIELD_SIZE;
+        assert_eq!(reader.univariate(0)[0], U256::from(first_univariate_field));
+        assert_eq!(
+            reader.univariate(CONST_PROOF_SIZE_LOG_N - 1)[BATCHED_RELATION_PARTIAL_LENGTH - 1],
+            U256::from(EVALUATIONS_OFFSET / FIELD_SIZE - 1)
+        );
+
+        let opening = reader.opening();
+        assert_eq!(opening.w1.x_0, U256::zero());
+        assert_eq!(opening.lookup_inverses.y_1, U256::from(first_univariate_field - 1));
+        assert_eq!(opening.sumcheck_evaluations[0], U256::from(EVALUATIONS_OFFSET / FIELD_SIZE));
+        assert_eq!(opening.kzg_quotient.y_1, U256::from(PROOF_SIZE / FIELD_SIZE - 1));
+    }
+
+    #[test]
+    fn test_rejects_short_or_non_canonical_proof() {
+        let mut bytes = counting_proof();
+        assert!(ProofReader::new(&bytes[..PROOF_SIZE - 1]).is_err());
+
+        bytes[UNIVARIATES_OFFSET..UNIVARIATES_OFFSET + FIELD_SIZE].copy_from_slice(&to_bytes_be(MODULUS));
+        assert!(matches!(ProofReader::new(&bytes), Err(VerifierError::InvalidProofFormat)));
+    }
+}
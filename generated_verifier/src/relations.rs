@@ -1,60 +1,96 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use crate::field::{Fr, add_mod, sub_mod, mul_mod, pow_mod, neg_mod};
+use crate::field::{Fr, add_mod, is_canonical, reduce_mod, sub_mod, mul_mod, pow_mod, MINUS_ONE, MINUS_TWO, MINUS_THREE, NEG_HALF};
+use crate::constants::{
+    NUMBER_OF_ALPHAS, NUMBER_OF_ENTITIES, NUMBER_OF_SUBRELATIONS, NUMBER_TO_BE_SHIFTED, NUMBER_UNSHIFTED,
+};
 use crate::transcript::RelationParameters;
+use ink::prelude::vec::Vec;
 use primitive_types::U256;
 
-const NUMBER_OF_SUBRELATIONS: usize = 26;
-const NUMBER_OF_ENTITIES: usize = 40;
-const NUMBER_OF_ALPHAS: usize = 25;
 
-/// Wire enum for indexing into the 40-element evaluation array
+/// Wire enum for indexing into the 40-element evaluation array.
+/// Follows the entity order of the proof's `sumcheck_evaluations`, which is
+/// also the order Shplemini batches the commitments in.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(usize)]
 pub enum Wire {
-    WL = 0,
-    WR = 1,
-    WO = 2,
-    W4 = 3,
-    WLShift = 4,
-    WRShift = 5,
-    WOShift = 6,
-    W4Shift = 7,
-    QL = 8,
-    QR = 9,
-    QO = 10,
-    Q4 = 11,
-    QM = 12,
-    QC = 13,
-    QArith = 14,
-    QRange = 15,
-    QElliptic = 16,
-    QLookup = 17,
-    QPoseidon2External = 18,
-    QPoseidon2Internal = 19,
-    Sigma1 = 20,
-    Sigma2 = 21,
-    Sigma3 = 22,
-    Sigma4 = 23,
-    Id1 = 24,
-    Id2 = 25,
-    Id3 = 26,
-    Id4 = 27,
-    Table1 = 28,
-    Table2 = 29,
-    Table3 = 30,
-    Table4 = 31,
-    LookupReadCounts = 32,
-    LookupReadTags = 33,
-    LookupInverses = 34,
-    ZPerm = 35,
-    ZPermShift = 36,
-    LagrangeFirst = 37,
-    LagrangeLast = 38,
-    // Add one more to make 40 total
-    _Reserved = 39,
+    QM = 0,
+    QC = 1,
+    QL = 2,
+    QR = 3,
+    QO = 4,
+    Q4 = 5,
+    QLookup = 6,
+    QArith = 7,
+    QRange = 8,
+    QElliptic = 9,
+    QAux = 10,
+    QPoseidon2External = 11,
+    QPoseidon2Internal = 12,
+    Sigma1 = 13,
+    Sigma2 = 14,
+    Sigma3 = 15,
+    Sigma4 = 16,
+    Id1 = 17,
+    Id2 = 18,
+    Id3 = 19,
+    Id4 = 20,
+    Table1 = 21,
+    Table2 = 22,
+    Table3 = 23,
+    Table4 = 24,
+    LagrangeFirst = 25,
+    LagrangeLast = 26,
+    WL = 27,
+    WR = 28,
+    WO = 29,
+    W4 = 30,
+    ZPerm = 31,
+    LookupInverses = 32,
+    LookupReadCounts = 33,
+    LookupReadTags = 34,
+    // Shifted evaluations, always the last NUMBER_TO_BE_SHIFTED entries
+    WLShift = 35,
+    WRShift = 36,
+    WOShift = 37,
+    W4Shift = 38,
+    ZPermShift = 39,
 }
 
+impl Wire {
+    /// Whether this entity is a shift of another (evaluated at `-r` in Shplemini)
+    pub const fn is_shifted(self) -> bool {
+        self as usize >= NUMBER_UNSHIFTED
+    }
+}
+
+// The shifted wires occupy exactly the last NUMBER_TO_BE_SHIFTED indices
+const _: () = assert!(Wire::LookupReadTags as usize == NUMBER_UNSHIFTED - 1);
+const _: () = assert!(Wire::WLShift as usize == NUMBER_UNSHIFTED);
+const _: () = assert!(Wire::ZPermShift as usize == NUMBER_OF_ENTITIES - 1);
+const _: () = assert!(NUMBER_OF_ENTITIES - NUMBER_UNSHIFTED == NUMBER_TO_BE_SHIFTED);
+
+/// -b for Grumpkin's `y^2 = x^3 - 17`, used by the elliptic relation
+const GRUMPKIN_B_NEG: Fr = U256([17, 0, 0, 0]);
+
+/// Poseidon2 internal matrix diagonal (minus one), as in the Solidity verifier
+const POSEIDON2_INTERNAL_DIAG: [Fr; 4] = [
+    U256([0xb56821fd19d3b6e7, 0x0d03f98929ca1d7f, 0x04b1e03b4bd9490c, 0x10dc6e9c006ea38b]),
+    U256([0xa86b38cfb45a740b, 0x99df9756d4dd9b84, 0x0149b3d0a30b3bb5, 0x0c28145b6a44df3e]),
+    U256([0x70067d00141cac15, 0xb21f75bb60e35961, 0xb2c7645a50392798, 0x00544b8338791518]),
+    U256([0x13bc534433ee428b, 0x52e105a3b8fa8526, 0x2e2e82eb122789e3, 0x222c01175718386f]),
+];
+
+const _: () = assert!(is_canonical(&GRUMPKIN_B_NEG));
+const _: () = {
+    let mut i = 0;
+    while i < POSEIDON2_INTERNAL_DIAG.len() {
+        assert!(is_canonical(&POSEIDON2_INTERNAL_DIAG[i]));
+        i += 1;
+    }
+};
+
 /// Main entry point for accumulating all relation evaluations
 pub fn accumulate_relation_evaluations(
     purported_evals: &[Fr; NUMBER_OF_ENTITIES],
@@ -62,6 +98,31 @@ pub fn accumulate_relation_evaluations(
     alphas: &[Fr; NUMBER_OF_ALPHAS],
     pow_partial_eval: Fr,
 ) -> Fr {
+    let evals = evaluate_subrelations(purported_evals, params, pow_partial_eval);
+    
+    // Batch subrelations with alpha challenges
+    scale_and_batch_subrelations(&evals, alphas)
+}
+
+/// Reduce every evaluation below `MODULUS`. The relations assume canonical
+/// inputs, which proof parsing already guarantees; this keeps them correct
+/// without relying on it.
+pub fn normalize_evals(evals: &mut [Fr; NUMBER_OF_ENTITIES]) {
+    for eval in evals.iter_mut() {
+        *eval = reduce_mod(*eval);
+    }
+}
+
+/// Evaluate every subrelation individually, before alpha batching
+pub fn evaluate_subrelations(
+    purported_evals: &[Fr; NUMBER_OF_ENTITIES],
+    params: &RelationParameters,
+    pow_partial_eval: Fr,
+) -> [Fr; NUMBER_OF_SUBRELATIONS] {
+    let mut normalized = *purported_evals;
+    normalize_evals(&mut normalized);
+    let purported_evals = &normalized;
+
     let mut evals = [U256::zero(); NUMBER_OF_SUBRELATIONS];
     
     // Accumulate each relation type
@@ -74,8 +135,65 @@ pub fn accumulate_relation_evaluations(
     accumulate_poseidon_external(purported_evals, &mut evals, pow_partial_eval);
     accumulate_poseidon_internal(purported_evals, &mut evals, pow_partial_eval);
     
-    // Batch subrelations with alpha challenges
-    scale_and_batch_subrelations(&evals, alphas)
+    evals
+}
+
+/// Subrelation outputs grouped by relation family, in `evaluate_subrelations` order.
+/// Meant for diffing each family against a reference verifier's intermediate dump.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RelationTrace {
+    pub arithmetic: [Fr; 2],
+    pub permutation: [Fr; 2],
+    pub lookup: [Fr; 2],
+    pub delta_range: [Fr; 4],
+    pub elliptic: [Fr; 2],
+    pub auxiliary: [Fr; 6],
+    pub poseidon_external: [Fr; 4],
+    pub poseidon_internal: [Fr; 4],
+}
+
+#[cfg(feature = "std")]
+impl RelationTrace {
+    /// Concatenate the families back into the flat subrelation array
+    pub fn flatten(&self) -> [Fr; NUMBER_OF_SUBRELATIONS] {
+        [
+            &self.arithmetic[..],
+            &self.permutation[..],
+            &self.lookup[..],
+            &self.delta_range[..],
+            &self.elliptic[..],
+            &self.auxiliary[..],
+            &self.poseidon_external[..],
+            &self.poseidon_internal[..],
+        ]
+        .concat()
+        .try_into()
+        .expect("families cover every subrelation")
+    }
+}
+
+/// Same as `evaluate_subrelations`, split per relation family
+#[cfg(feature = "std")]
+pub fn accumulate_relation_evaluations_traced(
+    purported_evals: &[Fr; NUMBER_OF_ENTITIES],
+    params: &RelationParameters,
+    pow_partial_eval: Fr,
+) -> RelationTrace {
+    let evals = evaluate_subrelations(purported_evals, params, pow_partial_eval);
+    let family = |start: usize, len: usize| &evals[start..start + len];
+    let copy = |dst: &mut [Fr], src: &[Fr]| dst.copy_from_slice(src);
+
+    let mut trace = RelationTrace::default();
+    copy(&mut trace.arithmetic, family(0, 2));
+    copy(&mut trace.permutation, family(2, 2));
+    copy(&mut trace.lookup, family(4, 2));
+    copy(&mut trace.delta_range, family(6, 4));
+    copy(&mut trace.elliptic, family(10, 2));
+    copy(&mut trace.auxiliary, family(12, 6));
+    copy(&mut trace.poseidon_external, family(18, 4));
+    copy(&mut trace.poseidon_internal, family(22, 4));
+    trace
 }
 
 /// Helper to access wire values by enum
@@ -89,13 +207,6 @@ fn accumulate_arithmetic_relation(
     evals: &mut [Fr; NUMBER_OF_SUBRELATIONS],
     domain_sep: Fr,
 ) {
-    // NEG_HALF constant - computed at runtime
-    fn neg_half() -> Fr {
-        U256::from_dec_str(
-            "10944121435919637611123202872628637544348155578649730659431676447034106383360"
-        ).unwrap()
-    }
-    
     let q_arith = wire(p, Wire::QArith);
     
     // Subrelation 0
@@ -104,7 +215,7 @@ fn accumulate_arithmetic_relation(
         accum = mul_mod(accum, wire(p, Wire::QM));
         accum = mul_mod(accum, wire(p, Wire::WR));
         accum = mul_mod(accum, wire(p, Wire::WL));
-        accum = mul_mod(accum, neg_half());
+        accum = mul_mod(accum, NEG_HALF);
         
         accum = add_mod(accum, mul_mod(wire(p, Wire::QL), wire(p, Wire::WL)));
         accum = add_mod(accum, mul_mod(wire(p, Wire::QR), wire(p, Wire::WR)));
@@ -262,10 +373,6 @@ fn accumulate_delta_range_relation(
     evals: &mut [Fr; NUMBER_OF_SUBRELATIONS],
     domain_sep: Fr,
 ) {
-    let minus_one = neg_mod(U256::one());
-let minus_two = neg_mod(U256::from(2));
-let minus_three = neg_mod(U256::from(3));
-    
     let delta_1 = sub_mod(wire(p, Wire::WR), wire(p, Wire::WL));
     let delta_2 = sub_mod(wire(p, Wire::WO), wire(p, Wire::WR));
     let delta_3 = sub_mod(wire(p, Wire::W4), wire(p, Wire::WO));
@@ -275,9 +382,9 @@ let minus_three = neg_mod(U256::from(3));
     
     // Helper to compute delta * (delta - 1) * (delta - 2) * (delta - 3)
     let mut eval_delta = |delta: Fr, index: usize| {
-        let mut acc = mul_mod(delta, add_mod(delta, minus_one));
-        acc = mul_mod(acc, add_mod(delta, minus_two));
-        acc = mul_mod(acc, add_mod(delta, minus_three));
+        let mut acc = mul_mod(delta, add_mod(delta, MINUS_ONE));
+        acc = mul_mod(acc, add_mod(delta, MINUS_TWO));
+        acc = mul_mod(acc, add_mod(delta, MINUS_THREE));
         acc = mul_mod(acc, q_range);
         acc = mul_mod(acc, domain_sep);
         evals[index] = acc;
@@ -295,11 +402,6 @@ fn accumulate_elliptic_relation(
     evals: &mut [Fr; NUMBER_OF_SUBRELATIONS],
     domain_sep: Fr,
 ) {
-    // GRUMPKIN_B_NEG constant
-    fn grumpkin_b_neg() -> Fr {
-        U256::from(17)
-    }
-    
     let x1 = wire(p, Wire::WR);
     let y1 = wire(p, Wire::WO);
     let x2 = wire(p, Wire::WLShift);
@@ -336,7 +438,7 @@ fn accumulate_elliptic_relation(
     
     // Point doubling (when q_is_double = 1)
     {
-        let x_pow_4 = mul_mod(add_mod(y1_sqr, grumpkin_b_neg()), x1);
+        let x_pow_4 = mul_mod(add_mod(y1_sqr, GRUMPKIN_B_NEG), x1);
         let y1_sqr_4 = mul_mod(U256::from(4), y1_sqr);
         let x1_pow_4_9 = mul_mod(x_pow_4, U256::from(9));
         
@@ -415,16 +517,6 @@ fn accumulate_poseidon_internal(
     evals: &mut [Fr; NUMBER_OF_SUBRELATIONS],
     domain_sep: Fr,
 ) {
-    // Internal round constants (from Solidity) - computed at runtime
-    fn diag() -> [Fr; 4] {
-        [
-            U256::from_str_radix("10dc6e9c006ea38b04b1e03b4bd9490c0d03f98929ca1d7fb56821fd19d3b6e7", 16).unwrap(),
-            U256::from_str_radix("0c28145b6a44df3e0149b3d0a30b3bb599df9756d4dd9b84a86b38cfb45a740b", 16).unwrap(),
-            U256::from_str_radix("00544b8338791518b2c7645a50392798b21f75bb60e3596170067d00141cac15", 16).unwrap(),
-            U256::from_str_radix("222c01175718386f2e2e82eb122789e352e105a3b8fa852613bc534433ee428b", 16).unwrap(),
-        ]
-    }
-    
     let s1 = add_mod(wire(p, Wire::WL), wire(p, Wire::QL));
     let u1 = pow_mod(s1, U256::from(5));
     let u2 = wire(p, Wire::WR);
@@ -433,7 +525,7 @@ fn accumulate_poseidon_internal(
     
     let u_sum = add_mod(add_mod(add_mod(u1, u2), u3), u4);
     let q_pos = mul_mod(wire(p, Wire::QPoseidon2Internal), domain_sep);
-    let diag = diag();
+    let diag = POSEIDON2_INTERNAL_DIAG;
     
     let v1 = add_mod(mul_mod(u1, diag[0]), u_sum);
     evals[22] = mul_mod(q_pos, sub_mod(v1, wire(p, Wire::WLShift)));
@@ -448,6 +540,18 @@ fn accumulate_poseidon_internal(
     evals[25] = mul_mod(q_pos, sub_mod(v4, wire(p, Wire::W4Shift)));
 }
 
+/// `[alpha, alpha^2, ..., alpha^n]`: the batching challenges when every
+/// subrelation is scaled by a power of one alpha (see `alpha-powers` in Cargo.toml)
+pub fn alpha_powers(alpha: Fr, n: usize) -> Vec<Fr> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = alpha;
+    for _ in 0..n {
+        powers.push(power);
+        power = mul_mod(power, alpha);
+    }
+    powers
+}
+
 /// Batch subrelations with alpha challenges
 fn scale_and_batch_subrelations(
     evals: &[Fr; NUMBER_OF_SUBRELATIONS],
@@ -461,3 +565,164 @@ fn scale_and_batch_subrelations(
     
     acc
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{neg_mod, MODULUS};
+    use crate::testutil::ProofBuilder;
+
+    /// 2 * 3 - 6 = 0 as a single arithmetic gate: qm * wl * wr + qo * wo
+    fn arithmetic_gate() -> ProofBuilder {
+        ProofBuilder::new()
+            .wire(Wire::QArith, U256::one())
+            .wire(Wire::QM, U256::one())
+            .wire(Wire::QO, neg_mod(U256::one()))
+            .wire(Wire::WL, U256::from(2))
+            .wire(Wire::WR, U256::from(3))
+            .wire(Wire::WO, U256::from(6))
+    }
+
+    #[test]
+    fn test_arithmetic_gate_satisfied() {
+        let builder = arithmetic_gate().relation_parameters_with(
+            RelationParameters::new(U256::zero(), U256::zero(), U256::zero(), U256::from(11), U256::from(13))
+                .with_public_input_delta(U256::zero()),
+        );
+        let evals = evaluate_subrelations(
+            &builder.evaluations(),
+            &builder.relation_parameters(),
+            U256::one(),
+        );
+        assert_eq!(evals[0], U256::zero());
+        assert_eq!(evals[1], U256::zero());
+
+        let batched = accumulate_relation_evaluations(
+            &builder.evaluations(),
+            &builder.relation_parameters(),
+            &[U256::from(7); NUMBER_OF_ALPHAS],
+            U256::one(),
+        );
+        assert_eq!(batched, U256::zero());
+    }
+
+    #[test]
+    fn test_non_canonical_evaluation_is_normalized() {
+        // Unsatisfied, so the batched value is nonzero and actually compared
+        let builder = arithmetic_gate().wire(Wire::WO, U256::from(5));
+        let mut wide = builder.evaluations();
+        wide[Wire::WO as usize] = MODULUS + U256::from(5);
+
+        let alphas: [Fr; NUMBER_OF_ALPHAS] = alpha_powers(U256::from(7), NUMBER_OF_ALPHAS).try_into().unwrap();
+        let batch = |evals: &[Fr; NUMBER_OF_ENTITIES]| {
+            accumulate_relation_evaluations(evals, &builder.relation_parameters(), &alphas, U256::one())
+        };
+        assert_ne!(batch(&builder.evaluations()), U256::zero());
+        assert_eq!(batch(&wide), batch(&builder.evaluations()));
+
+        normalize_evals(&mut wide);
+        assert_eq!(wide, builder.evaluations());
+    }
+
+    #[test]
+    fn test_arithmetic_gate_unsatisfied() {
+        let builder = arithmetic_gate().wire(Wire::WO, U256::from(5));
+        let proof = builder.build();
+        let evals = evaluate_subrelations(
+            &proof.opening.sumcheck_evaluations,
+            &builder.relation_parameters(),
+            U256::one(),
+        );
+        // 2 * 3 - 5 = 1
+        assert_eq!(evals[0], U256::one());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_traced_families_concatenate_to_flat() {
+        let builder = arithmetic_gate()
+            .wire(Wire::WO, U256::from(5))
+            .wire(Wire::QRange, U256::one())
+            .wire(Wire::W4, U256::from(9));
+        let evals = builder.evaluations();
+        let params = builder.relation_parameters();
+
+        let trace = accumulate_relation_evaluations_traced(&evals, &params, U256::one());
+
+        assert_eq!(trace.arithmetic[0], U256::one());
+        assert_ne!(trace.delta_range, [U256::zero(); 4]);
+        assert_eq!(trace.flatten(), evaluate_subrelations(&evals, &params, U256::one()));
+    }
+
+    #[test]
+    fn test_shifted_wires_follow_unshifted() {
+        assert!(Wire::WLShift as usize >= NUMBER_UNSHIFTED);
+        assert!(Wire::ZPermShift.is_shifted());
+        assert!(!Wire::ZPerm.is_shifted());
+
+        let proof = ProofBuilder::new()
+            .wire(Wire::WL, U256::from(1))
+            .wire(Wire::WLShift, U256::from(2))
+            .build();
+        assert_eq!(proof.unshifted_evaluations().len(), NUMBER_UNSHIFTED);
+        assert_eq!(proof.shifted_evaluations().len(), NUMBER_TO_BE_SHIFTED);
+        assert_eq!(proof.unshifted_evaluations()[Wire::WL as usize], U256::from(1));
+        assert_eq!(proof.shifted_evaluations()[0], U256::from(2));
+    }
+
+    #[test]
+    fn test_alpha_powers() {
+        assert_eq!(
+            alpha_powers(U256::from(3), 4),
+            [3u64, 9, 27, 81].map(U256::from).to_vec()
+        );
+        assert!(alpha_powers(U256::from(3), 0).is_empty());
+        assert_eq!(alpha_powers(MINUS_ONE, 3), vec![MINUS_ONE, U256::one(), MINUS_ONE]);
+    }
+
+    #[test]
+    fn test_batching_with_independent_alphas_and_powers() {
+        let mut evals = [U256::zero(); NUMBER_OF_SUBRELATIONS];
+        evals[0] = U256::from(1);
+        evals[1] = U256::from(2);
+        evals[2] = U256::from(3);
+        evals[NUMBER_OF_SUBRELATIONS - 1] = U256::from(4);
+
+        // Independent: 1 + 2 * 5 + 3 * 7 + 4 * 11
+        let mut independent = [U256::zero(); NUMBER_OF_ALPHAS];
+        independent[0] = U256::from(5);
+        independent[1] = U256::from(7);
+        independent[NUMBER_OF_ALPHAS - 1] = U256::from(11);
+        assert_eq!(scale_and_batch_subrelations(&evals, &independent), U256::from(76));
+
+        // Powers of 2: 1 + 2 * 2 + 3 * 4 + 4 * 2^25
+        let powers: [Fr; NUMBER_OF_ALPHAS] = alpha_powers(U256::from(2), NUMBER_OF_ALPHAS).try_into().unwrap();
+        assert_eq!(scale_and_batch_subrelations(&evals, &powers), U256::from(17 + (4u64 << 25)));
+    }
+
+    #[test]
+    fn test_relation_constants_match_solidity() {
+        // Literals as they appear in the Solidity verifier
+        let diag = [
+            "10dc6e9c006ea38b04b1e03b4bd9490c0d03f98929ca1d7fb56821fd19d3b6e7",
+            "0c28145b6a44df3e0149b3d0a30b3bb599df9756d4dd9b84a86b38cfb45a740b",
+            "00544b8338791518b2c7645a50392798b21f75bb60e3596170067d00141cac15",
+            "222c01175718386f2e2e82eb122789e352e105a3b8fa852613bc534433ee428b",
+        ];
+        for (constant, literal) in POSEIDON2_INTERNAL_DIAG.iter().zip(diag) {
+            assert_eq!(*constant, U256::from_str_radix(literal, 16).unwrap());
+            assert!(is_canonical(constant));
+        }
+
+        assert_eq!(GRUMPKIN_B_NEG, U256::from(17));
+        let half_modulus_minus_one = U256::from_dec_str(
+            "10944121435919637611123202872628637544274182200208017171849102093287904247808",
+        )
+        .unwrap();
+        assert_eq!(NEG_HALF, half_modulus_minus_one);
+        for constant in [GRUMPKIN_B_NEG, NEG_HALF, MINUS_ONE, MINUS_TWO, MINUS_THREE] {
+            assert!(is_canonical(&constant));
+        }
+    }
+}
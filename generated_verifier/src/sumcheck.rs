@@ -0,0 +1,111 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use primitive_types::U256;
+use crate::constants::BATCHED_RELATION_PARTIAL_LENGTH;
+use crate::errors::{VerifierError, VerifierResult};
+use crate::field::{add_mod, mul_mod, sub_mod, try_inv_mod, Fr};
+
+/// Each round univariate `p` must satisfy `p(0) + p(1) == target`
+pub fn check_round_sum(univariate: &[Fr; BATCHED_RELATION_PARTIAL_LENGTH], target: Fr) -> VerifierResult<()> {
+    if add_mod(univariate[0], univariate[1]) != target {
+        return Err(VerifierError::SumcheckFailed);
+    }
+    Ok(())
+}
+
+/// `d_i = prod_{j != i} (i - j)` over the domain `0..8`, reduced mod p:
+/// -5040, 720, -240, 144, -144, 240, -720, 5040
+const BARYCENTRIC_DENOMINATORS: [Fr; BATCHED_RELATION_PARTIAL_LENGTH] = [
+    U256([0x43e1f593efffec51, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029]),
+    U256([0x2d0, 0, 0, 0]),
+    U256([0x43e1f593efffff11, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029]),
+    U256([0x90, 0, 0, 0]),
+    U256([0x43e1f593efffff71, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029]),
+    U256([0xf0, 0, 0, 0]),
+    U256([0x43e1f593effffd31, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029]),
+    U256([0x13b0, 0, 0, 0]),
+];
+
+/// Evaluate at `challenge` the polynomial given by its values on `0..8`, via
+/// the barycentric formula
+///     p(x) = B(x) * sum_i p(i) / (d_i * (x - i)),  B(x) = prod_i (x - i)
+///
+/// Eight points determine any polynomial up to degree 7, the degree of the full
+/// gate set (`MAX_RELATION_DEGREE`), so lower-degree relations fit as well.
+pub fn evaluate_univariate(evaluations: &[Fr; BATCHED_RELATION_PARTIAL_LENGTH], challenge: Fr) -> VerifierResult<Fr> {
+    // On the domain itself the formula divides by zero; the value is given
+    if challenge < U256::from(BATCHED_RELATION_PARTIAL_LENGTH) {
+        return Ok(evaluations[challenge.as_usize()]);
+    }
+
+    let mut numerator = U256::one();
+    for i in 0..BATCHED_RELATION_PARTIAL_LENGTH {
+        numerator = mul_mod(numerator, sub_mod(challenge, U256::from(i)));
+    }
+
+    let mut sum = U256::zero();
+    for (i, (evaluation, denominator)) in evaluations.iter().zip(BARYCENTRIC_DENOMINATORS).enumerate() {
+        let denominator = mul_mod(denominator, sub_mod(challenge, U256::from(i)));
+        let inverse = try_inv_mod(denominator).ok_or(VerifierError::DivisionByZero)?;
+        sum = add_mod(sum, mul_mod(*evaluation, inverse));
+    }
+
+    Ok(mul_mod(sum, numerator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::pow_mod;
+
+    /// `coefficients[k] * x^k` summed, evaluated directly
+    fn eval_poly(coefficients: &[u64], x: Fr) -> Fr {
+        coefficients.iter().enumerate().fold(U256::zero(), |acc, (k, c)| {
+            add_mod(acc, mul_mod(U256::from(*c), pow_mod(x, U256::from(k))))
+        })
+    }
+
+    /// The values on `0..8` of the polynomial with these coefficients
+    fn points(coefficients: &[u64]) -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH] {
+        core::array::from_fn(|i| eval_poly(coefficients, U256::from(i)))
+    }
+
+    #[test]
+    fn test_barycentric_denominators() {
+        for (i, denominator) in BARYCENTRIC_DENOMINATORS.iter().enumerate() {
+            let expected = (0..BATCHED_RELATION_PARTIAL_LENGTH)
+                .filter(|&j| j != i)
+                .fold(U256::one(), |acc, j| mul_mod(acc, sub_mod(U256::from(i), U256::from(j))));
+            assert_eq!(*denominator, expected);
+        }
+    }
+
+    #[test]
+    fn test_degree_three_univariate() {
+        // A reduced relation set: p(x) = 5 + 2x + 7x^2 + x^3 still sent as 8 values
+        let coefficients = [5, 2, 7, 1];
+        let points = points(&coefficients);
+        let challenge = U256::from(123_456_789u64);
+
+        assert_eq!(evaluate_univariate(&points, challenge), Ok(eval_poly(&coefficients, challenge)));
+        assert_eq!(evaluate_univariate(&points, U256::from(2)), Ok(points[2]));
+    }
+
+    #[test]
+    fn test_full_degree_univariate() {
+        let coefficients = [3, 1, 4, 1, 5, 9, 2, 6];
+        let challenge = U256::from(987_654_321u64);
+
+        assert_eq!(
+            evaluate_univariate(&points(&coefficients), challenge),
+            Ok(eval_poly(&coefficients, challenge))
+        );
+    }
+
+    #[test]
+    fn test_check_round_sum() {
+        let univariate = points(&[2, 1]);
+        assert_eq!(check_round_sum(&univariate, U256::from(5)), Ok(()));
+        assert_eq!(check_round_sum(&univariate, U256::from(6)), Err(VerifierError::SumcheckFailed));
+    }
+}
@@ -0,0 +1,69 @@
+//! Test-only helpers for building proofs with hand-picked evaluations.
+
+use primitive_types::U256;
+use crate::field::Fr;
+use crate::constants::NUMBER_OF_ENTITIES;
+use crate::relations::Wire;
+use crate::transcript::{Proof, ProofOpening, RelationParameters};
+
+/// Builds the purported sumcheck evaluations fed to the relation accumulator,
+/// one `Wire` at a time. Every wire not set explicitly evaluates to zero.
+#[derive(Clone, Copy, Debug)]
+pub struct ProofBuilder {
+    evaluations: [Fr; NUMBER_OF_ENTITIES],
+    relation_parameters: RelationParameters,
+}
+
+impl Default for ProofBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProofBuilder {
+    pub fn new() -> Self {
+        Self {
+            evaluations: [U256::zero(); NUMBER_OF_ENTITIES],
+            relation_parameters: RelationParameters::new(
+                U256::zero(),
+                U256::zero(),
+                U256::zero(),
+                U256::zero(),
+                U256::zero(),
+            )
+            .with_public_input_delta(U256::zero()),
+        }
+    }
+
+    /// Set the evaluation of a single wire
+    pub fn wire(mut self, w: Wire, value: Fr) -> Self {
+        self.evaluations[w as usize] = value;
+        self
+    }
+
+    /// Set the relation parameters (eta, beta, gamma, ...)
+    pub fn relation_parameters_with(mut self, params: RelationParameters) -> Self {
+        self.relation_parameters = params;
+        self
+    }
+
+    /// The `[Fr; NUMBER_OF_ENTITIES]` array consumed by `accumulate_relation_evaluations`
+    pub fn evaluations(&self) -> [Fr; NUMBER_OF_ENTITIES] {
+        self.evaluations
+    }
+
+    pub fn relation_parameters(&self) -> RelationParameters {
+        self.relation_parameters
+    }
+
+    /// A `Proof` carrying these evaluations; every commitment is left at its default
+    pub fn build(&self) -> Proof {
+        Proof {
+            opening: ProofOpening {
+                sumcheck_evaluations: self.evaluations,
+                ..ProofOpening::default()
+            },
+            ..Proof::default()
+        }
+    }
+}
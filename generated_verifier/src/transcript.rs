@@ -2,21 +2,35 @@
 
 use ink::prelude::vec::Vec;
 use primitive_types::U256;
-use crate::field::{Fr, to_bytes_be};
+use crate::constants::{
+    BATCHED_RELATION_PARTIAL_LENGTH, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ALPHAS, NUMBER_OF_ENTITIES,
+    NUMBER_UNSHIFTED, PAIRING_POINTS_SIZE,
+};
+use crate::errors::{VerifierError, VerifierResult};
+use crate::field::{add_mod, from_bytes_be, DecodeFr, mul_mod, reduce_mod, sub_mod, to_bytes_be, try_div_mod, Fr, MODULUS};
 use crate::honk_structs::*;
+use crate::relations::alpha_powers;
 
 
-// Circuit constants
-const CONST_PROOF_SIZE_LOG_N: usize = 28;
-const NUMBER_OF_SUBRELATIONS: usize = 26;
-const BATCHED_RELATION_PARTIAL_LENGTH: usize = 8;
-const NUMBER_OF_ENTITIES: usize = 40;
-const NUMBER_UNSHIFTED: usize = 35;
-const NUMBER_TO_BE_SHIFTED: usize = 5;
-const NUMBER_OF_ALPHAS: usize = 25;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A parsed proof, about 14KB (`PROOF_FOOTPRINT`). Half of that is the
+/// univariates, the rest the opening, which is nested rather than flattened so
+/// the later stages can borrow it instead of copying it out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Proof {
+    pub opening: ProofOpening,
+    pub sumcheck_univariates: [[Fr; BATCHED_RELATION_PARTIAL_LENGTH]; CONST_PROOF_SIZE_LOG_N],
+}
+
+/// Bytes a `Proof` takes on the stack. PolkaVM's stack is small enough that
+/// one stray by-value copy matters: take `&Proof` / `&ProofOpening`, never the
+/// struct itself.
+pub const PROOF_FOOTPRINT: usize = core::mem::size_of::<Proof>();
+
+/// Everything in a `Proof` except the sumcheck univariates: what the transcript
+/// and Shplemini need once sumcheck rounds are consumed one at a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofOpening {
     pub w1: G1ProofPoint,
     pub w2: G1ProofPoint,
     pub w3: G1ProofPoint,
@@ -25,7 +39,6 @@ pub struct Proof {
     pub lookup_read_counts: G1ProofPoint,
     pub lookup_read_tags: G1ProofPoint,
     pub lookup_inverses: G1ProofPoint,
-    pub sumcheck_univariates: [[Fr; BATCHED_RELATION_PARTIAL_LENGTH]; CONST_PROOF_SIZE_LOG_N],
     pub sumcheck_evaluations: [Fr; NUMBER_OF_ENTITIES],
     pub gemini_fold_comms: [G1ProofPoint; CONST_PROOF_SIZE_LOG_N - 1],
     pub gemini_a_evaluations: [Fr; CONST_PROOF_SIZE_LOG_N],
@@ -33,9 +46,9 @@ pub struct Proof {
     pub kzg_quotient: G1ProofPoint,
 }
 
-impl Default for Proof {
+impl Default for ProofOpening {
     fn default() -> Self {
-        Proof {
+        ProofOpening {
             w1: G1ProofPoint::default(),
             w2: G1ProofPoint::default(),
             w3: G1ProofPoint::default(),
@@ -44,7 +57,6 @@ impl Default for Proof {
             lookup_read_counts: G1ProofPoint::default(),
             lookup_read_tags: G1ProofPoint::default(),
             lookup_inverses: G1ProofPoint::default(),
-            sumcheck_univariates: [[U256::zero(); BATCHED_RELATION_PARTIAL_LENGTH]; CONST_PROOF_SIZE_LOG_N],
             sumcheck_evaluations: [U256::zero(); NUMBER_OF_ENTITIES],
             gemini_fold_comms: [G1ProofPoint::default(); CONST_PROOF_SIZE_LOG_N - 1],
             gemini_a_evaluations: [U256::zero(); CONST_PROOF_SIZE_LOG_N],
@@ -54,7 +66,7 @@ impl Default for Proof {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Transcript {
     pub relation_parameters: RelationParameters,
     pub alphas: [Fr; NUMBER_OF_ALPHAS as usize],
@@ -66,7 +78,78 @@ pub struct Transcript {
     pub shplonk_z: Fr,
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+impl Transcript {
+    /// Every challenge `verify` reads for a circuit of `log_n` rounds is
+    /// nonzero and canonical; a sanity check on externally supplied transcripts
+    pub fn has_challenges_for(&self, log_n: usize) -> bool {
+        let params = &self.relation_parameters;
+        log_n <= CONST_PROOF_SIZE_LOG_N
+            && [params.eta, params.eta_two, params.eta_three, params.beta, params.gamma]
+                .iter()
+                .chain(&self.alphas)
+                .chain(&self.gate_challenges[..log_n])
+                .chain(&self.sumcheck_u_challenges[..log_n])
+                .chain([&self.rho, &self.gemini_r, &self.shplonk_nu, &self.shplonk_z])
+                .all(|challenge| !challenge.is_zero() && *challenge < MODULUS)
+    }
+
+    /// Every challenge by name, in the order `generate` derives them. The
+    /// public input delta is derived right after gamma, so it is listed there.
+    pub fn challenges(&self) -> [(&'static str, &[Fr]); 13] {
+        self.challenges_for(CONST_PROOF_SIZE_LOG_N)
+    }
+
+    /// `challenges`, with the per-round arrays cut to the `log_n` rounds a
+    /// circuit of that size reads; the rest is padding
+    pub fn challenges_for(&self, log_n: usize) -> [(&'static str, &[Fr]); 13] {
+        let rounds = log_n.min(CONST_PROOF_SIZE_LOG_N);
+        let params = &self.relation_parameters;
+        [
+            ("eta", core::slice::from_ref(&params.eta)),
+            ("eta_two", core::slice::from_ref(&params.eta_two)),
+            ("eta_three", core::slice::from_ref(&params.eta_three)),
+            ("beta", core::slice::from_ref(&params.beta)),
+            ("gamma", core::slice::from_ref(&params.gamma)),
+            ("public_inputs_delta", core::slice::from_ref(&params.public_inputs_delta)),
+            ("alphas", &self.alphas),
+            ("gate_challenges", &self.gate_challenges[..rounds]),
+            ("sumcheck_u_challenges", &self.sumcheck_u_challenges[..rounds]),
+            ("rho", core::slice::from_ref(&self.rho)),
+            ("gemini_r", core::slice::from_ref(&self.gemini_r)),
+            ("shplonk_nu", core::slice::from_ref(&self.shplonk_nu)),
+            ("shplonk_z", core::slice::from_ref(&self.shplonk_z)),
+        ]
+    }
+}
+
+/// Name of the first challenge, in derivation order, on which two transcripts
+/// disagree; `None` if they match. Each challenge depends on the ones before
+/// it, so this is where the two sides' Fiat-Shamir inputs first differ. For
+/// the arrays the name is the whole array's, as in `Transcript::challenges`.
+/// Only the first `log_n` gate and sumcheck challenges are compared.
+pub fn diff_transcripts(ours: &Transcript, theirs: &Transcript, log_n: usize) -> Option<&'static str> {
+    ours.challenges_for(log_n)
+        .iter()
+        .zip(theirs.challenges_for(log_n).iter())
+        .find(|((_, ours), (_, theirs))| ours != theirs)
+        .map(|((name, _), _)| *name)
+}
+
+impl Proof {
+    /// Evaluations of the unshifted entities, in `Wire` order
+    pub fn unshifted_evaluations(&self) -> &[Fr] {
+        &self.opening.sumcheck_evaluations[..NUMBER_UNSHIFTED]
+    }
+
+    /// Evaluations of the shifted entities (`WLShift..=ZPermShift`)
+    pub fn shifted_evaluations(&self) -> &[Fr] {
+        &self.opening.sumcheck_evaluations[NUMBER_UNSHIFTED..]
+    }
+}
+
+/// No `Default`: a zero `public_inputs_delta` silently breaks the permutation
+/// relation, so parameters are only built via `new(..).with_public_input_delta(..)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct RelationParameters {
     pub eta: Fr,
     pub eta_two: Fr,
@@ -76,6 +159,38 @@ pub struct RelationParameters {
     pub public_inputs_delta: Fr,
 }
 
+/// Relation challenges still waiting for the public input delta
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[must_use = "call `with_public_input_delta` to get the RelationParameters"]
+pub struct PendingRelationParameters {
+    eta: Fr,
+    eta_two: Fr,
+    eta_three: Fr,
+    beta: Fr,
+    gamma: Fr,
+}
+
+impl RelationParameters {
+    // Returns the pending half on purpose, so the delta can't be skipped
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(eta: Fr, eta_two: Fr, eta_three: Fr, beta: Fr, gamma: Fr) -> PendingRelationParameters {
+        PendingRelationParameters { eta, eta_two, eta_three, beta, gamma }
+    }
+}
+
+impl PendingRelationParameters {
+    pub fn with_public_input_delta(self, public_inputs_delta: Fr) -> RelationParameters {
+        RelationParameters {
+            eta: self.eta,
+            eta_two: self.eta_two,
+            eta_three: self.eta_three,
+            beta: self.beta,
+            gamma: self.gamma,
+            public_inputs_delta,
+        }
+    }
+}
+
 /// Split a 256-bit challenge into two 128-bit challenges
 fn split_challenge(challenge: Fr) -> (Fr, Fr) {
     let lo_mask = U256::from_dec_str(
@@ -88,44 +203,156 @@ fn split_challenge(challenge: Fr) -> (Fr, Fr) {
     (lo, hi)
 }
 
-/// Hash using SHA256 (via precompile)
+/// Hash using Keccak256 and reduce the digest into the field.
+/// Matches `FrLib.fromBytes32(keccak256(...))` in the Solidity verifier,
+/// which is what `bb` produces with `--oracle_hash keccak`.
 fn hash_to_field(data: &[u8]) -> Fr {
-    // In actual implementation, call SHA256 precompile
-    // For now, simplified
-    use ink::env::hash::{HashOutput, Sha2x256};
-    let mut output = <Sha2x256 as HashOutput>::Type::default();
-    ink::env::hash_bytes::<Sha2x256>(data, &mut output);
-    let mut hash_bytes = [0u8; 32];
-    hash_bytes.copy_from_slice(&output[..32]);
-    U256::from_big_endian(&hash_bytes)
+    use ink::env::hash::{HashOutput, Keccak256};
+    let mut output = <Keccak256 as HashOutput>::Type::default();
+    ink::env::hash_bytes::<Keccak256>(data, &mut output);
+    reduce_mod(U256::from_big_endian(&output))
+}
+
+/// Every public input must be exactly one 32-byte field: a longer or shorter
+/// entry would shift everything the transcript absorbs after it
+pub fn check_public_input_lengths(public_inputs: &[Vec<u8>]) -> VerifierResult<()> {
+    match public_inputs.iter().position(|input| input.len() != 32) {
+        Some(index) => Err(VerifierError::InvalidPublicInputFormat { index: index as u32 }),
+        None => Ok(()),
+    }
+}
+
+/// Grand product delta contributed by the public inputs to the permutation argument.
+/// `Transcript::generate` caches this in `RelationParameters`; exposed so the
+/// cached value can be checked independently.
+pub fn recompute_public_input_delta(
+    public_inputs: &[Vec<u8>],
+    beta: Fr,
+    gamma: Fr,
+    circuit_size: Fr,
+    offset: Fr,
+) -> VerifierResult<Fr> {
+    let mut numerator = U256::one();
+    let mut denominator = U256::one();
+
+    let mut numerator_acc = add_mod(gamma, mul_mod(beta, add_mod(circuit_size, offset)));
+    let mut denominator_acc = sub_mod(gamma, mul_mod(beta, add_mod(offset, U256::one())));
+
+    for (index, input) in public_inputs.iter().enumerate() {
+        let bytes: &[u8; 32] = input
+            .as_slice()
+            .try_into()
+            .map_err(|_| VerifierError::InvalidPublicInputFormat { index: index as u32 })?;
+        let pub_input = from_bytes_be(bytes);
+
+        numerator = mul_mod(numerator, add_mod(numerator_acc, pub_input));
+        denominator = mul_mod(denominator, add_mod(denominator_acc, pub_input));
+
+        numerator_acc = add_mod(numerator_acc, beta);
+        denominator_acc = sub_mod(denominator_acc, beta);
+    }
+
+    try_div_mod(numerator, denominator).ok_or(VerifierError::DivisionByZero)
+}
+
+/// Public inputs are laid out as
+///
+/// ```text
+/// [ user inputs | pairing-point limbs (PAIRING_POINTS_SIZE, recursive only) ]
+/// ```
+///
+/// and `vk.public_inputs_size` counts both parts. The transcript absorbs them
+/// in that order, and `recompute_public_input_delta` assigns them consecutive
+/// wire indices starting at `pub_inputs_offset`, so the pairing-point limbs are
+/// hashed and permuted exactly like user inputs. In recursive settings bb puts
+/// the inner verifier's pairing-point accumulator in the last 16 entries; this
+/// splits them off so the caller can fold them into the final pairing.
+pub fn split_pairing_point_inputs(
+    public_inputs: &[Vec<u8>],
+) -> VerifierResult<(&[Vec<u8>], [Fr; PAIRING_POINTS_SIZE])> {
+    let Some(user_count) = public_inputs.len().checked_sub(PAIRING_POINTS_SIZE) else {
+        return Err(VerifierError::InvalidPublicInputsLength {
+            expected: PAIRING_POINTS_SIZE as u32,
+            got: public_inputs.len() as u32,
+        });
+    };
+    check_public_input_lengths(public_inputs)?;
+    let (user_inputs, pairing_inputs) = public_inputs.split_at(user_count);
+
+    let mut limbs = [U256::zero(); PAIRING_POINTS_SIZE];
+    for (limb, input) in limbs.iter_mut().zip(pairing_inputs) {
+        *limb = input.as_slice().decode_fr()?;
+    }
+    Ok((user_inputs, limbs))
+}
+
+/// Batching scalar for folding a recursive proof's pairing points `(lhs, rhs)`
+/// into this proof's accumulator `(acc_lhs, acc_rhs)`: keccak over all eight
+/// coordinates, as the Solidity verifier's `generateRecursionSeparator`.
+pub fn recursion_separator(lhs: &G1Point, rhs: &G1Point, acc_lhs: &G1Point, acc_rhs: &G1Point) -> Fr {
+    let mut data = Vec::with_capacity(8 * 32);
+    absorb_commitments(&mut data, &[*lhs, *rhs, *acc_lhs, *acc_rhs]);
+    hash_to_field(&data)
+}
+
+/// Append each commitment as `x || y`, 32 bytes big-endian per coordinate
+fn absorb_commitments(data: &mut Vec<u8>, commitments: &[G1Point]) {
+    for point in commitments {
+        data.extend_from_slice(&to_bytes_be(point.x));
+        data.extend_from_slice(&to_bytes_be(point.y));
+    }
 }
 
 impl Transcript {
-    /// Generate complete transcript from proof and public inputs
+    /// Generate complete transcript from proof and public inputs.
+    ///
+    /// `public_inputs_delta` only depends on values known after the beta/gamma
+    /// round, so it is computed once here rather than in the permutation relation.
     pub fn generate(
         proof: &Proof,
         public_inputs: &[Vec<u8>],
-        circuit_size: Fr,
-        public_inputs_size: Fr,
+        vk: &VerificationKey,
         pub_inputs_offset: Fr,
-    ) -> Self {
+    ) -> VerifierResult<Self> {
+        Self::generate_streaming(
+            &proof.opening,
+            |round| proof.sumcheck_univariates[round],
+            public_inputs,
+            vk,
+            pub_inputs_offset,
+        )
+    }
+
+    /// `generate`, with each round's sumcheck univariate fetched on demand by
+    /// `univariate(round)` instead of held in a full `Proof`
+    pub fn generate_streaming(
+        proof: &ProofOpening,
+        univariate: impl Fn(usize) -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH],
+        public_inputs: &[Vec<u8>],
+        vk: &VerificationKey,
+        pub_inputs_offset: Fr,
+    ) -> VerifierResult<Self> {
+        // Only CONST_PROOF_SIZE_LOG_N rounds of challenges are derived below
+        vk.log_n()?;
+
         // Generate eta challenges
-        let (eta, eta_two, eta_three, prev) = 
-            Self::generate_eta_challenge(proof, public_inputs, circuit_size, public_inputs_size, pub_inputs_offset);
+        let (eta, eta_two, eta_three, prev) =
+            Self::generate_eta_challenge(proof, public_inputs, vk, pub_inputs_offset)?;
         let mut prev_challenge = prev;
         
         // Generate beta and gamma
         let (beta, gamma, prev) = Self::generate_beta_gamma(prev_challenge, proof);
         prev_challenge = prev;
         
-        let relation_parameters = RelationParameters {
-            eta,
-            eta_two,
-            eta_three,
+        let public_inputs_delta = recompute_public_input_delta(
+            public_inputs,
             beta,
             gamma,
-            public_inputs_delta: U256::zero(), // Computed later
-        };
+            vk.circuit_size,
+            pub_inputs_offset,
+        )?;
+        let relation_parameters = RelationParameters::new(eta, eta_two, eta_three, beta, gamma)
+            .with_public_input_delta(public_inputs_delta);
         
         // Generate alphas
         let (alphas, prev) = Self::generate_alphas(prev_challenge, proof);
@@ -136,7 +363,7 @@ impl Transcript {
         prev_challenge = prev;
         
         // Generate sumcheck challenges
-        let (sumcheck_u_challenges, prev) = Self::generate_sumcheck_challenges(proof, prev_challenge);
+        let (sumcheck_u_challenges, prev) = Self::generate_sumcheck_challenges(univariate, prev_challenge);
         prev_challenge = prev;
         
         // Generate rho
@@ -153,7 +380,7 @@ impl Transcript {
         
         let (shplonk_z, _) = Self::generate_shplonk_z(proof, prev_challenge);
         
-        Self {
+        Ok(Self {
             relation_parameters,
             alphas,
             gate_challenges,
@@ -162,21 +389,32 @@ impl Transcript {
             gemini_r,
             shplonk_nu,
             shplonk_z,
-        }
+        })
     }
     
+    /// Round 0: absorb the circuit parameters, public inputs and `w1..w3`.
+    ///
+    /// As in Barretenberg, `eta`/`eta_two` are the low/high halves of the first
+    /// hash and `eta_three` is the low half of the hash of that challenge; the
+    /// high half of the second hash is discarded.
+    ///
+    /// Fails if `public_inputs` doesn't match the VK's `public_inputs_size` or an
+    /// entry isn't 32 bytes, so a truncated or padded input set can't silently
+    /// produce a different challenge.
     fn generate_eta_challenge(
-        proof: &Proof,
+        proof: &ProofOpening,
         public_inputs: &[Vec<u8>],
-        circuit_size: Fr,
-        public_inputs_size: Fr,
+        vk: &VerificationKey,
         pub_inputs_offset: Fr,
-    ) -> (Fr, Fr, Fr, Fr) {
+    ) -> VerifierResult<(Fr, Fr, Fr, Fr)> {
+        vk.check_public_inputs_len(public_inputs.len())?;
+        check_public_input_lengths(public_inputs)?;
+
         let mut data = Vec::new();
         
         // Add circuit parameters
-        data.extend_from_slice(&to_bytes_be(circuit_size));
-        data.extend_from_slice(&to_bytes_be(public_inputs_size));
+        data.extend_from_slice(&to_bytes_be(vk.circuit_size));
+        data.extend_from_slice(&to_bytes_be(vk.public_inputs_size));
         data.extend_from_slice(&to_bytes_be(pub_inputs_offset));
         
         // Add public inputs
@@ -206,10 +444,10 @@ impl Transcript {
         let next_challenge = hash_to_field(&to_bytes_be(challenge));
         let (eta_three, _) = split_challenge(next_challenge);
         
-        (eta, eta_two, eta_three, next_challenge)
+        Ok((eta, eta_two, eta_three, next_challenge))
     }
     
-    fn generate_beta_gamma(prev_challenge: Fr, proof: &Proof) -> (Fr, Fr, Fr) {
+    fn generate_beta_gamma(prev_challenge: Fr, proof: &ProofOpening) -> (Fr, Fr, Fr) {
         let mut data = Vec::new();
         data.extend_from_slice(&to_bytes_be(prev_challenge));
         
@@ -235,7 +473,10 @@ impl Transcript {
         (beta, gamma, challenge)
     }
     
-    fn generate_alphas(prev_challenge: U256, proof: &Proof) -> ([Fr; NUMBER_OF_ALPHAS as usize], Fr) {
+    /// Subrelation batching challenges. bb 0.82.x splits each of a chain of
+    /// hashes into two alphas; bb 1.0.0 on takes one alpha and its powers,
+    /// selected with the `alpha-powers` feature (see Cargo.toml).
+    fn generate_alphas(prev_challenge: U256, proof: &ProofOpening) -> ([Fr; NUMBER_OF_ALPHAS as usize], Fr) {
         let mut alphas = [U256::zero(); NUMBER_OF_ALPHAS as usize];
         let mut challenge = prev_challenge;
         
@@ -251,11 +492,23 @@ impl Transcript {
         data.extend_from_slice(&to_bytes_be(proof.z_perm.y_1));
         
         challenge = hash_to_field(&data);
+        if cfg!(feature = "alpha-powers") {
+            let (alpha, _) = split_challenge(challenge);
+            alphas.copy_from_slice(&alpha_powers(alpha, NUMBER_OF_ALPHAS));
+            return (alphas, challenge);
+        }
+
         (alphas[0], alphas[1]) = split_challenge(challenge);
         
-        for i in (2..NUMBER_OF_ALPHAS as usize).step_by(2) {
+        for i in 1..NUMBER_OF_ALPHAS / 2 {
             challenge = hash_to_field(&to_bytes_be(challenge));
-            (alphas[i], alphas[i + 1]) = split_challenge(challenge);
+            (alphas[2 * i], alphas[2 * i + 1]) = split_challenge(challenge);
+        }
+        
+        // An odd alpha count takes the low half of one extra hash
+        if NUMBER_OF_ALPHAS % 2 == 1 {
+            challenge = hash_to_field(&to_bytes_be(challenge));
+            (alphas[NUMBER_OF_ALPHAS - 1], _) = split_challenge(challenge);
         }
         
         (alphas, challenge)
@@ -272,7 +525,10 @@ impl Transcript {
         (challenges, prev_challenge)
     }
     
-    fn generate_sumcheck_challenges(proof: &Proof, mut prev_challenge: U256) -> ([Fr; CONST_PROOF_SIZE_LOG_N as usize], Fr) {
+    fn generate_sumcheck_challenges(
+        univariate: impl Fn(usize) -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH],
+        mut prev_challenge: U256,
+    ) -> ([Fr; CONST_PROOF_SIZE_LOG_N as usize], Fr) {
         let mut challenges = [U256::zero(); CONST_PROOF_SIZE_LOG_N as usize];
         
         for i in 0..CONST_PROOF_SIZE_LOG_N as usize {
@@ -280,8 +536,8 @@ impl Transcript {
             data.extend_from_slice(&to_bytes_be(prev_challenge));
             
             // Add univariate evaluations for this round
-            for j in 0..BATCHED_RELATION_PARTIAL_LENGTH as usize {
-                data.extend_from_slice(&to_bytes_be(proof.sumcheck_univariates[i][j]));
+            for evaluation in univariate(i) {
+                data.extend_from_slice(&to_bytes_be(evaluation));
             }
             
             prev_challenge = hash_to_field(&data);
@@ -291,7 +547,7 @@ impl Transcript {
         (challenges, prev_challenge)
     }
     
-    fn generate_rho(proof: &Proof, prev_challenge: Fr) -> (Fr, Fr) {
+    fn generate_rho(proof: &ProofOpening, prev_challenge: Fr) -> (Fr, Fr) {
         let mut data = Vec::new();
         data.extend_from_slice(&to_bytes_be(prev_challenge));
         
@@ -305,7 +561,7 @@ impl Transcript {
         (rho, challenge)
     }
     
-    fn generate_gemini_r(proof: &Proof, prev_challenge: Fr) -> (Fr, Fr) {
+    fn generate_gemini_r(proof: &ProofOpening, prev_challenge: Fr) -> (Fr, Fr) {
         let mut data = Vec::new();
         data.extend_from_slice(&to_bytes_be(prev_challenge));
         
@@ -322,7 +578,7 @@ impl Transcript {
         (gemini_r, challenge)
     }
     
-    fn generate_shplonk_nu(proof: &Proof, prev_challenge: Fr) -> (Fr, Fr) {
+    fn generate_shplonk_nu(proof: &ProofOpening, prev_challenge: Fr) -> (Fr, Fr) {
         let mut data = Vec::new();
         data.extend_from_slice(&to_bytes_be(prev_challenge));
         
@@ -336,7 +592,7 @@ impl Transcript {
         (nu, challenge)
     }
     
-    fn generate_shplonk_z(proof: &Proof, prev_challenge: Fr) -> (Fr, Fr) {
+    fn generate_shplonk_z(proof: &ProofOpening, prev_challenge: Fr) -> (Fr, Fr) {
         let mut data = Vec::new();
         data.extend_from_slice(&to_bytes_be(prev_challenge));
         data.extend_from_slice(&to_bytes_be(proof.shplonk_q.x_0));
@@ -350,3 +606,280 @@ impl Transcript {
         (z, challenge)
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proof_point(first: u64) -> G1ProofPoint {
+        G1ProofPoint {
+            x_0: U256::from(first),
+            x_1: U256::from(first + 1),
+            y_0: U256::from(first + 2),
+            y_1: U256::from(first + 3),
+        }
+    }
+
+    fn vk() -> VerificationKey {
+        VerificationKey {
+            circuit_size: U256::from(32),
+            log_circuit_size: U256::from(5),
+            public_inputs_size: U256::one(),
+            ..VerificationKey::default()
+        }
+    }
+
+    #[test]
+    fn test_split_challenge_halves() {
+        let mut buf = [0u8; 32];
+        buf[..16].copy_from_slice(&[0xaa; 16]);
+        buf[16..].copy_from_slice(&[0x55; 16]);
+        let (lo, hi) = split_challenge(U256::from_big_endian(&buf));
+
+        // `lo` is the trailing 16 bytes and `hi` the leading 16 bytes of the big-endian encoding
+        assert_eq!(lo, U256::from_big_endian(&[0x55; 16]));
+        assert_eq!(hi, U256::from_big_endian(&[0xaa; 16]));
+        assert_eq!(&to_bytes_be(hi)[16..], &buf[..16]);
+    }
+
+    #[test]
+    fn test_eta_challenges_pinned() {
+        let proof = ProofOpening {
+            w1: proof_point(1),
+            w2: proof_point(5),
+            w3: proof_point(9),
+            // Lookup commitments are only absorbed from the beta/gamma round on
+            lookup_read_counts: proof_point(13),
+            lookup_read_tags: proof_point(17),
+            ..ProofOpening::default()
+        };
+        let public_inputs = [to_bytes_be(U256::from(42)).to_vec()];
+
+        let (eta, eta_two, eta_three, prev) =
+            Transcript::generate_eta_challenge(&proof, &public_inputs, &vk(), U256::one()).unwrap();
+
+        // Reference values from a standalone Python port of HonkVerifier.sol's
+        // `generateEtaChallenge` over the same inputs, with its own Keccak-256
+        assert_eq!(eta, U256::from_str_radix("71302468737da3f464543c451b3d76ed", 16).unwrap());
+        assert_eq!(eta_two, U256::from_str_radix("10a6860f16fef38b7eec758aad57b5ef", 16).unwrap());
+        assert_eq!(eta_three, U256::from_str_radix("6a791c4f570bfa0b54e3918ae25d4953", 16).unwrap());
+        assert_eq!(
+            prev,
+            U256::from_str_radix(
+                "102b1c8ac72eaa75aaa871542f673b6a791c4f570bfa0b54e3918ae25d4953",
+                16
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cached_public_input_delta_matches_recompute() {
+        let opening = ProofOpening { w1: proof_point(1), w4: proof_point(5), ..ProofOpening::default() };
+        let proof = Proof { opening, ..Proof::default() };
+        let public_inputs = [to_bytes_be(U256::from(42)).to_vec()];
+
+        let transcript = Transcript::generate(&proof, &public_inputs, &vk(), U256::one()).unwrap();
+        let params = transcript.relation_parameters;
+        let recomputed = recompute_public_input_delta(
+            &public_inputs,
+            params.beta,
+            params.gamma,
+            U256::from(32),
+            U256::one(),
+        )
+        .unwrap();
+
+        assert_ne!(params.public_inputs_delta, U256::zero());
+        assert_eq!(params.public_inputs_delta, recomputed);
+    }
+
+    #[test]
+    fn test_eta_challenge_binds_circuit_size() {
+        let proof = ProofOpening { w1: proof_point(1), ..ProofOpening::default() };
+        let original = vk();
+        let tampered = VerificationKey { circuit_size: U256::from(64), ..original };
+
+        let public_inputs = [to_bytes_be(U256::from(42)).to_vec()];
+
+        let (eta, ..) =
+            Transcript::generate_eta_challenge(&proof, &public_inputs, &original, U256::one()).unwrap();
+        let (tampered_eta, ..) =
+            Transcript::generate_eta_challenge(&proof, &public_inputs, &tampered, U256::one()).unwrap();
+
+        assert_ne!(eta, tampered_eta);
+    }
+
+    #[test]
+    fn test_eta_challenge_rejects_missing_public_inputs() {
+        let vk = VerificationKey { public_inputs_size: U256::from(4), ..vk() };
+        let public_inputs = [to_bytes_be(U256::from(42)).to_vec()];
+
+        assert_eq!(
+            Transcript::generate_eta_challenge(&ProofOpening::default(), &public_inputs, &vk, U256::one()),
+            Err(VerifierError::InvalidPublicInputsLength { expected: 4, got: 1 })
+        );
+    }
+
+    #[test]
+    fn test_public_inputs_size_comes_from_vk() {
+        // The Solidity verifier this was ported from hardcoded 4 public inputs
+        let vk = vk();
+        assert_eq!(vk.public_inputs_size, U256::one());
+        let public_inputs = vec![to_bytes_be(U256::from(42)).to_vec(); 4];
+
+        assert_eq!(
+            Transcript::generate_eta_challenge(&ProofOpening::default(), &public_inputs, &vk, U256::one()),
+            Err(VerifierError::InvalidPublicInputsLength { expected: 1, got: 4 })
+        );
+        assert!(Transcript::generate(&Proof::default(), &public_inputs[..1], &vk, U256::one()).is_ok());
+    }
+
+    #[test]
+    fn test_public_input_consumers_reject_non_32_byte_entries() {
+        let vk = VerificationKey { public_inputs_size: U256::from(3), ..vk() };
+        let field = to_bytes_be(U256::from(42)).to_vec();
+
+        let mut long = field.clone();
+        long.push(0);
+        for (public_inputs, index) in [
+            ([field.clone(), long, field.clone()], 1),
+            ([field.clone(), field.clone(), Vec::new()], 2),
+        ] {
+            let expected = VerifierError::InvalidPublicInputFormat { index };
+            assert_eq!(
+                Transcript::generate_eta_challenge(&ProofOpening::default(), &public_inputs, &vk, U256::one()),
+                Err(expected)
+            );
+            assert_eq!(
+                recompute_public_input_delta(&public_inputs, U256::from(11), U256::from(13), U256::from(32), U256::one()),
+                Err(expected)
+            );
+            assert_eq!(check_public_input_lengths(&public_inputs), Err(expected));
+        }
+    }
+
+    #[test]
+    fn test_generate_rejects_oversized_log_circuit_size() {
+        let vk = VerificationKey {
+            log_circuit_size: U256::from(30),
+            circuit_size: U256::one() << 30,
+            ..vk()
+        };
+        assert!(matches!(
+            Transcript::generate(&Proof::default(), &[vec![0u8; 32]], &vk, U256::one()),
+            Err(VerifierError::InvalidVerificationKey)
+        ));
+    }
+
+    #[test]
+    fn test_alphas_follow_batching_feature() {
+        let (alphas, _) = Transcript::generate_alphas(U256::from(5), &ProofOpening::default());
+        let powers_of_first = alpha_powers(alphas[0], NUMBER_OF_ALPHAS);
+        assert_eq!(alphas.as_slice() == powers_of_first.as_slice(), cfg!(feature = "alpha-powers"));
+    }
+
+    #[test]
+    fn test_generated_transcript_has_challenges_for_log_n() {
+        let vk = vk();
+        let mut transcript = Transcript::generate(&Proof::default(), &[vec![0u8; 32]], &vk, U256::one()).unwrap();
+        assert!(transcript.has_challenges_for(vk.log_n().unwrap()));
+        assert!(!transcript.has_challenges_for(CONST_PROOF_SIZE_LOG_N + 1));
+
+        transcript.sumcheck_u_challenges[0] = MODULUS;
+        assert!(!transcript.has_challenges_for(1));
+        assert!(transcript.has_challenges_for(0));
+
+        // Rounds past log_n are padding and never read
+        transcript.gate_challenges[1] = U256::zero();
+        assert!(!transcript.has_challenges_for(2));
+        assert!(transcript.has_challenges_for(0));
+
+        transcript.rho = U256::zero();
+        assert!(!transcript.has_challenges_for(0));
+    }
+
+    #[test]
+    fn test_proof_footprint_pinned() {
+        // 8 + 27 + 2 points of four limbs, 40 + 28 evaluations, 28 univariates of 8
+        assert_eq!(core::mem::size_of::<ProofOpening>(), 6_912);
+        assert_eq!(PROOF_FOOTPRINT, 6_912 + 7_168);
+        // 6 relation parameters, the alphas, 28 gate and 28 sumcheck challenges, 4 more
+        assert_eq!(core::mem::size_of::<Transcript>(), (6 + NUMBER_OF_ALPHAS + 2 * 28 + 4) * 32);
+    }
+
+    #[test]
+    fn test_diff_transcripts_reports_first_divergence() {
+        let proof = Proof { opening: ProofOpening { w1: proof_point(1), ..ProofOpening::default() }, ..Proof::default() };
+        let ours = Transcript::generate(&proof, &[vec![0u8; 32]], &vk(), U256::one()).unwrap();
+        assert_eq!(diff_transcripts(&ours, &ours, 5), None);
+
+        let mut theirs = ours;
+        theirs.relation_parameters.gamma = add_mod(theirs.relation_parameters.gamma, U256::one());
+        assert_eq!(diff_transcripts(&ours, &theirs, 5), Some("gamma"));
+
+        // Later divergences don't mask the first
+        theirs.shplonk_z = U256::zero();
+        theirs.sumcheck_u_challenges[3] = U256::zero();
+        assert_eq!(diff_transcripts(&ours, &theirs, 5), Some("gamma"));
+        theirs.relation_parameters.gamma = ours.relation_parameters.gamma;
+        assert_eq!(diff_transcripts(&ours, &theirs, 5), Some("sumcheck_u_challenges"));
+
+        // Rounds past log_n are padding
+        assert_eq!(diff_transcripts(&ours, &theirs, 3), Some("shplonk_z"));
+        theirs.shplonk_z = ours.shplonk_z;
+        theirs.gate_challenges[27] = U256::zero();
+        assert_eq!(diff_transcripts(&ours, &theirs, 3), None);
+    }
+
+    #[test]
+    fn test_relation_parameters_carry_delta() {
+        let (beta, gamma) = (U256::from(11), U256::from(13));
+        let public_inputs = [to_bytes_be(U256::from(42)).to_vec()];
+        let delta =
+            recompute_public_input_delta(&public_inputs, beta, gamma, U256::from(32), U256::one()).unwrap();
+
+        let params = RelationParameters::new(U256::one(), U256::from(2), U256::from(3), beta, gamma)
+            .with_public_input_delta(delta);
+
+        assert_ne!(params.public_inputs_delta, U256::zero());
+        assert_eq!(params.public_inputs_delta, delta);
+        assert_eq!((params.eta, params.beta, params.gamma), (U256::one(), beta, gamma));
+    }
+
+    #[test]
+    fn test_split_pairing_point_inputs() {
+        let public_inputs: Vec<Vec<u8>> =
+            (0..PAIRING_POINTS_SIZE as u64 + 2).map(|i| to_bytes_be(U256::from(i)).to_vec()).collect();
+
+        let (user_inputs, limbs) = split_pairing_point_inputs(&public_inputs).unwrap();
+        assert_eq!(user_inputs, &public_inputs[..2]);
+        assert_eq!(limbs[0], U256::from(2));
+        assert_eq!(limbs[PAIRING_POINTS_SIZE - 1], U256::from(PAIRING_POINTS_SIZE as u64 + 1));
+
+        assert_eq!(
+            split_pairing_point_inputs(&public_inputs[..3]).map(|_| ()),
+            Err(VerifierError::InvalidPublicInputsLength { expected: 16, got: 3 })
+        );
+    }
+
+    #[test]
+    fn test_recursion_separator_pinned() {
+        let g = G1Point { x: U256::one(), y: U256::from(2) };
+        let neg_g = G1Point { x: U256::one(), y: crate::curve::Q - U256::from(2) };
+        let separator = recursion_separator(
+            &g,
+            &neg_g,
+            &G1Point { x: U256::from(3), y: U256::from(4) },
+            &G1Point { x: U256::from(5), y: U256::from(6) },
+        );
+
+        // From a standalone Python port of HonkVerifier.sol's
+        // `generateRecursionSeparator`, with its own Keccak-256
+        assert_eq!(
+            separator,
+            U256::from_str_radix("2ea3fe486a17875a419815a73ff08faac829eb0a350715e5d0b5effe2a0abce5", 16).unwrap()
+        );
+    }
+}
@@ -1,4 +1,11 @@
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use generated_verifier::audit;
+use generated_verifier::errors::VerifierError;
+use generated_verifier::constants::{CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ALPHAS};
+use generated_verifier::field::to_bytes_be;
+use generated_verifier::honk_structs::{self, Fr};
+use generated_verifier::transcript::{diff_transcripts, RelationParameters, Transcript};
+use sha3::{Digest, Keccak256};
 use std::fs;
 use std::io::{Error, ErrorKind};
 use std::path::PathBuf;
@@ -9,13 +16,74 @@ const FIELD_SIZE: usize = 32;
 /// Generates an ink! v6 verifier smart contract from a Noir VK
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// Path to the Noir VK file
-    #[arg(short, long)]
-    vk: PathBuf,
+    #[arg(short, long, required_unless_present = "vks", conflicts_with = "vks")]
+    vk: Option<PathBuf>,
+    /// Directory of VK files, all the same length, for a router contract whose
+    /// `verify` takes a `circuit_id`: the file's index in name order
+    #[arg(long, conflicts_with = "with_cache")]
+    vks: Option<PathBuf>,
     /// Path to write the generated lib.rs file
-    #[arg(short, long)]
-    output: PathBuf,
+    #[arg(short, long, required = true)]
+    output: Option<PathBuf>,
+    /// How the VK file is encoded
+    #[arg(long, value_enum, default_value_t = VkFormat::BbRaw)]
+    vk_format: VkFormat,
+    /// Add a cache of accepted proofs: `verify_cached` and owner-only `clear_cache`
+    #[arg(long)]
+    with_cache: bool,
+}
+
+/// Byte layout of the source VK file
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum VkFormat {
+    /// Flat big-endian 32-byte fields, as written by Barretenberg
+    BbRaw,
+    /// Flat 32-byte fields, each little-endian
+    LeRaw,
+    /// Big-endian fields after a 4-byte big-endian field count
+    FieldPrefixed,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print the VK sizes and commitments as JSON, without generating anything
+    VkInfo {
+        /// Path to the Noir VK file
+        #[arg(short, long)]
+        vk: PathBuf,
+        /// How the VK file is encoded
+        #[arg(long, value_enum, default_value_t = VkFormat::BbRaw)]
+        vk_format: VkFormat,
+    },
+    /// Check every VK and proof field is canonical, without verifying anything
+    Audit {
+        /// Path to the Noir VK file
+        #[arg(short, long)]
+        vk: PathBuf,
+        /// Path to the proof file
+        #[arg(short, long)]
+        proof: PathBuf,
+        /// How the VK file is encoded
+        #[arg(long, value_enum, default_value_t = VkFormat::BbRaw)]
+        vk_format: VkFormat,
+    },
+    /// Name the first challenge on which two transcript dumps (JSON) disagree
+    DiffTranscript {
+        /// Path to our transcript dump
+        #[arg(long)]
+        ours: PathBuf,
+        /// Path to the transcript dump to compare against
+        #[arg(long)]
+        theirs: PathBuf,
+        /// The circuit's log size: how many gate and sumcheck challenges count
+        #[arg(long)]
+        log_n: usize,
+    },
 }
 
 /// The VK is just a falt array of field elements
@@ -24,47 +92,190 @@ struct VerificationKey {
     fields: Vec<[u8; 32]>,
 }
 
+impl VerificationKey {
+    /// The VK as the contract reads it (`VerificationKey::from_bb_fields`),
+    /// after the same range checks as `audit` and the contract's `self_test`,
+    /// so a VK that reads back here is one the contract accepts
+    fn to_structured(&self) -> Result<honk_structs::VerificationKey, Error> {
+        if self.fields.len() != honk_structs::BB_VK_FIELD_COUNT {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Expected {} VK field elements, got {}",
+                    honk_structs::BB_VK_FIELD_COUNT,
+                    self.fields.len()
+                ),
+            ));
+        }
+        audit_ranges(self, &[])?;
+
+        let vk = honk_structs::VerificationKey::from_bb_fields(&self.fields)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("Malformed VK header: {:?}", e)))?;
+        honk_structs::self_test(&vk).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "VK failed its self test: circuit size too large or a commitment off the curve",
+            )
+        })?;
+        Ok(vk)
+    }
+}
+
 fn main() {
     let args = Args::parse();
 
+    match args.command {
+        Some(Command::VkInfo { vk, vk_format }) => {
+            let vk_bytes = fs::read(&vk).expect("Failed to read VK file");
+            let vk = parse_vk(&vk_bytes, vk_format).expect("Failed to parse VK file");
+            let vk = vk.to_structured().expect("Invalid VK");
+            println!("{}", vk_info_json(&vk));
+            return;
+        }
+        Some(Command::Audit { vk, proof, vk_format }) => {
+            let vk_bytes = fs::read(&vk).expect("Failed to read VK file");
+            let vk = parse_vk(&vk_bytes, vk_format).expect("Failed to parse VK file");
+            let proof_bytes = fs::read(&proof).expect("Failed to read proof file");
+            match audit_ranges(&vk, &proof_bytes) {
+                Ok(()) => println!("All VK and proof fields are canonical."),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(Command::DiffTranscript { ours, theirs, log_n }) => {
+            let read = |path: &PathBuf| {
+                let json = fs::read_to_string(path).expect("Failed to read transcript dump");
+                transcript_from_json(&json, log_n).unwrap_or_else(|e| panic!("Failed to parse {:?}: {}", path, e))
+            };
+            match describe_divergence(&read(&ours), &read(&theirs), log_n) {
+                None => println!("Transcripts match."),
+                Some(divergence) => {
+                    println!("{}", divergence);
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        None => {}
+    }
+
+    // Required unless a subcommand is given, which clap enforces
+    let output = args.output.expect("--output is required");
+    if let Some(dir) = args.vks {
+        generate_router(&dir, args.vk_format, &output);
+        return;
+    }
+    let vk_path = args.vk.expect("--vk is required");
+
     println!("Starting Honk verifier generator...");
-    println!("      -> Reading VK from: {:?}", args.vk);
-    // println!("      -> Writing contract to: {:?}", args.output);
+    println!("      -> Reading VK from: {:?}", vk_path);
+    // println!("      -> Writing contract to: {:?}", output);
 
     // Read the vk file
-    let vk_bytes = fs::read(&args.vk).expect("Failed to read VK file");
+    let vk_bytes = fs::read(&vk_path).expect("Failed to read VK file");
     println!("      -> Read {} bytes.", vk_bytes.len());
 
     // Parse the VK bytes (flexible size)
-    let vk = parse_vk(&vk_bytes).expect("Failed to parse VK file");
+    let vk = parse_vk(&vk_bytes, args.vk_format).expect("Failed to parse VK file");
     println!(
         "      -> Successfully parsed VK with {} field elements.",
         vk.fields.len()
     );
     
-    // Show first few elements for debugging
+    // The contract rejects a VK that fails these checks, so fail here instead
+    let structured = vk.to_structured().expect("Invalid VK");
     println!("\n        VK Structure:");
-    if vk.fields.len() >= 3 {
-        println!("         Circuit size: 0x{}", hex_encode_last_bytes(&vk.fields[0], 4));
-        println!("         Log size:     0x{}", hex_encode_last_bytes(&vk.fields[1], 4));
-        println!("         Pub inputs:   0x{}", hex_encode_last_bytes(&vk.fields[2], 4));
-    }
+    println!("         Circuit size: {}", structured.circuit_size);
+    println!("         Log size:     {}", structured.log_circuit_size);
+    println!("         Pub inputs:   {}", structured.public_inputs_size);
 
     // Generate the contract code
-    let contract_code = generate_contract_code(&vk);
+    let contract_code = generate_contract_code(&vk, args.with_cache);
+    check_injected_vk(&contract_code, vk.fields.len())
+        .expect("Generated VK does not match VK_LEN");
 
     // Write the code to the output file
-    fs::write(&args.output, contract_code).expect("Failed to write output file");
+    fs::write(&output, contract_code).expect("Failed to write output file");
 
     println!(
         "Success! ink! v6 verifier contract generated at {:?}",
-        args.output
+        output
     );
     println!("   VK Length: {} field elements", vk.fields.len());
 }
 
-/// Parses the flat Barretenberg Honk vk file (flexible size)
-fn parse_vk(vk_bytes: &[u8]) -> Result<VerificationKey, Error> {
+/// Generate a router contract from every VK file in `dir`, circuit ids
+/// following file name order
+fn generate_router(dir: &PathBuf, format: VkFormat, output: &PathBuf) {
+    println!("Starting Honk router generator...");
+    println!("      -> Reading VKs from: {:?}", dir);
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .expect("Failed to read VK directory")
+        .map(|entry| entry.expect("Failed to read VK directory").path())
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+
+    let vks: Vec<VerificationKey> = paths
+        .iter()
+        .map(|path| {
+            let vk_bytes = fs::read(path).expect("Failed to read VK file");
+            parse_vk(&vk_bytes, format).unwrap_or_else(|e| panic!("Failed to parse {:?}: {}", path, e))
+        })
+        .collect();
+    check_router_vks(&vks).expect("VKs cannot share a router");
+    for (circuit_id, path) in paths.iter().enumerate() {
+        println!("         Circuit {}: {:?}", circuit_id, path);
+    }
+
+    let contract_code = generate_router_code(&vks);
+    check_injected_vk(&contract_code, vks[0].fields.len())
+        .expect("Generated VK does not match VK_LEN");
+
+    fs::write(output, contract_code).expect("Failed to write output file");
+
+    println!(
+        "Success! ink! v6 router contract generated at {:?}",
+        output
+    );
+    println!("   Circuits: {}, VK Length: {} field elements", vks.len(), vks[0].fields.len());
+}
+
+/// Routed VKs share the contract's `VK_LEN`, so they must all be one length,
+/// and each must pass the same checks as a single VK (`to_structured`)
+fn check_router_vks(vks: &[VerificationKey]) -> Result<(), Error> {
+    let Some(first) = vks.first() else {
+        return Err(Error::new(ErrorKind::InvalidData, "No VK files found"));
+    };
+    for (circuit_id, vk) in vks.iter().enumerate() {
+        if vk.fields.len() != first.fields.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "VK for circuit {} has {} field elements, circuit 0 has {}",
+                    circuit_id,
+                    vk.fields.len(),
+                    first.fields.len()
+                ),
+            ));
+        }
+        vk.to_structured()
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("Circuit {}: {}", circuit_id, e)))?;
+    }
+    Ok(())
+}
+
+/// Parses a Honk vk file (flexible size) into big-endian fields
+fn parse_vk(vk_bytes: &[u8], format: VkFormat) -> Result<VerificationKey, Error> {
+    let vk_bytes = match format {
+        VkFormat::FieldPrefixed => strip_field_count(vk_bytes)?,
+        VkFormat::BbRaw | VkFormat::LeRaw => vk_bytes,
+    };
+
     if vk_bytes.len() % FIELD_SIZE != 0 {
         return Err(Error::new(
             ErrorKind::InvalidData,
@@ -91,44 +302,369 @@ fn parse_vk(vk_bytes: &[u8]) -> Result<VerificationKey, Error> {
         .chunks_exact(FIELD_SIZE)
         .map(|chunk| {
             // Convert slice &[u8] to fixed-size array [u8; 32]
-            chunk.try_into().expect("Chunk size is guaranteed to be 32")
+            let mut field: [u8; 32] = chunk.try_into().expect("Chunk size is guaranteed to be 32");
+            if format == VkFormat::LeRaw {
+                field.reverse();
+            }
+            field
         })
         .collect();
 
     Ok(VerificationKey { fields })
 }
 
-/// inject the VK fields into the ink! template
-fn generate_contract_code(vk: &VerificationKey) -> String {
-    let template = include_str!("../templates/verifier.rs.template");
+/// Drop the 4-byte big-endian field count, checking it against the body
+fn strip_field_count(vk_bytes: &[u8]) -> Result<&[u8], Error> {
+    let Some((count, body)) = vk_bytes.split_first_chunk::<4>() else {
+        return Err(Error::new(ErrorKind::InvalidData, "VK too small for a field count prefix"));
+    };
+    let count = u32::from_be_bytes(*count) as usize;
+    if count * FIELD_SIZE != body.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "VK prefix claims {} fields but {} bytes follow",
+                count,
+                body.len()
+            ),
+        ));
+    }
+    Ok(body)
+}
+
+/// The contract's `audit::audit_ranges` on a decoded VK, with its error as a message
+fn audit_ranges(vk: &VerificationKey, proof_bytes: &[u8]) -> Result<(), Error> {
+    audit::audit_ranges(vk.fields.as_flattened(), proof_bytes).map_err(|e| {
+        let message = match e {
+            VerifierError::VkFieldOutOfRange { offset } => {
+                format!("VK field at byte offset {} is out of range", offset)
+            }
+            VerifierError::ProofFieldOutOfRange { offset } => {
+                format!("Proof field at byte offset {} is not below the scalar modulus", offset)
+            }
+            VerifierError::InvalidProofFormat => {
+                format!("Proof size must be a multiple of {} bytes, got {}", FIELD_SIZE, proof_bytes.len())
+            }
+            other => format!("{:?}", other),
+        };
+        Error::new(ErrorKind::InvalidData, message)
+    })
+}
+
+/// Optional contract sections, each filling the `%%NAME%%` lines of the template
+const CACHE_SECTIONS: [(&str, &str); 4] = [
+    ("%%CACHE_IMPORTS%%", include_str!("../templates/cache/imports.rs.template")),
+    ("%%CACHE_STORAGE%%", include_str!("../templates/cache/storage.rs.template")),
+    ("%%CACHE_INIT%%", include_str!("../templates/cache/init.rs.template")),
+    ("%%CACHE_MESSAGES%%", include_str!("../templates/cache/messages.rs.template")),
+];
+
+/// inject the VK fields into the ink! template, with the verification cache
+/// sections filled in if `with_cache` and dropped otherwise
+fn generate_contract_code(vk: &VerificationKey, with_cache: bool) -> String {
+    let mut template = include_str!("../templates/verifier.rs.template").to_string();
+    for (placeholder, section) in CACHE_SECTIONS {
+        let section = if with_cache { section } else { "" };
+        template = template.replace(&format!("{}\n", placeholder), section);
+    }
+    let template = keep_block(&template, "SINGLE_VK", true)
+        .replace("%%ROUTER_VKS%%\n", "")
+        .replace("%%ROUTER_VERIFY%%\n", "");
+
+    inject_vk(template, vk)
+}
 
-    // Format the VK fields
-    let vk_fields_string = vk
-        .fields
+/// The template with `verify` taking a `circuit_id` into `vks`. The first VK is
+/// the contract's own, which every other message checks against and `set_vk`
+/// replaces; the rest are embedded as `ROUTED_VKS`.
+/// Single-VK tests are dropped, as they assume the one-argument `verify`.
+fn generate_router_code(vks: &[VerificationKey]) -> String {
+    let mut template = include_str!("../templates/verifier.rs.template").to_string();
+    for (placeholder, _) in CACHE_SECTIONS {
+        template = template.replace(&format!("{}\n", placeholder), "");
+    }
+
+    let routed_vks: String = vks[1..]
         .iter()
-        .map(|field| {
-            // Format each 32-byte array: "[0x..., 0x..., ...]"
-            format!("[{}]", bytes_to_rust_hex_string(field))
+        .enumerate()
+        .map(|(i, vk)| {
+            format!(
+                "    const VK_BYTES_{}: &[u8; VK_LEN * 32] = b\"\\\n        {}\";\n",
+                i + 1,
+                vk_bytes_string(vk)
+            )
         })
-        .collect::<Vec<String>>()
-        .join(",\n    ");
+        .collect();
+    let routed_list: Vec<String> = (1..vks.len())
+        .map(|i| format!("split_vk_fields(VK_BYTES_{})", i))
+        .collect();
+    let routed_vks = format!(
+        "{}    /// VKs `verify` dispatches on past circuit 0, the contract's own\n    \
+         const ROUTED_VKS: [[[u8; 32]; VK_LEN]; {}] = [{}];\n",
+        routed_vks,
+        vks.len() - 1,
+        routed_list.join(", ")
+    );
+
+    let template = keep_block(&template, "SINGLE_VK", false)
+        .replace("%%ROUTER_VKS%%\n", &routed_vks)
+        .replace(
+            "%%ROUTER_VERIFY%%\n",
+            include_str!("../templates/router/verify.rs.template"),
+        );
 
+    inject_vk(template, &vks[0])
+}
+
+/// Fill in `VK_LEN` and the contract's own `VK_BYTES`
+fn inject_vk(template: String, vk: &VerificationKey) -> String {
     // Inject the VK length (actual number of field elements)
     let template = template.replace("%%VK_LEN%%", &vk.fields.len().to_string());
 
-    // Inject the VK fields
-    let template = template.replace("%%VK_FIELDS%%", &vk_fields_string);
+    // Inject the VK bytes
+    let template = template.replace("%%VK_BYTES%%", &vk_bytes_string(vk));
 
-    template
+    // Inject the digest the tests expect `circuit_info` to report
+    let mut hasher = Keccak256::new();
+    vk.fields.iter().for_each(|field| hasher.update(field));
+    template.replace("%%VK_HASH%%", &bytes_to_escaped_string(&hasher.finalize()))
 }
 
-// Helper function to turn a byte array into a hex string
-fn bytes_to_rust_hex_string(bytes: &[u8]) -> String {
-    bytes
+/// Format the VK as a byte-string body, one field per escaped line.
+/// Far smaller than a nested array of `0x..` literals to parse and lower.
+fn vk_bytes_string(vk: &VerificationKey) -> String {
+    vk.fields
         .iter()
-        .map(|b| format!("0x{:02x}", b))
+        .map(|field| bytes_to_escaped_string(field))
         .collect::<Vec<String>>()
-        .join(", ")
+        .join("\\\n        ")
+}
+
+/// Strip the `%%NAME_BEGIN%%` / `%%NAME_END%%` marker lines, and with them
+/// everything in between unless `keep`
+fn keep_block(template: &str, name: &str, keep: bool) -> String {
+    let begin = format!("%%{}_BEGIN%%\n", name);
+    let end = format!("%%{}_END%%\n", name);
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(&begin) {
+        out.push_str(&rest[..start]);
+        let inner = &rest[start + begin.len()..];
+        let stop = inner.find(&end).expect("unterminated template block");
+        if keep {
+            out.push_str(&inner[..stop]);
+        }
+        rest = &inner[stop + end.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Check that the generated code declares `VK_LEN` as `expected` and embeds exactly
+/// that many 32-byte fields, so a template substitution bug can't slip through.
+/// The contract's `&[u8; VK_LEN * 32]` type catches the same mismatch, but only
+/// once someone tries to build it.
+fn check_injected_vk(code: &str, expected: usize) -> Result<(), Error> {
+    let invalid = |msg: String| Error::new(ErrorKind::InvalidData, msg);
+
+    let len_decl = "const VK_LEN: usize = ";
+    let start = code
+        .find(len_decl)
+        .ok_or_else(|| invalid("VK_LEN declaration not found".to_string()))?
+        + len_decl.len();
+    let end = start
+        + code[start..]
+            .find(';')
+            .ok_or_else(|| invalid("Unterminated VK_LEN declaration".to_string()))?;
+    let declared: usize = code[start..end]
+        .trim()
+        .parse()
+        .map_err(|_| invalid(format!("Invalid VK_LEN value: {}", &code[start..end])))?;
+
+    let embedded = embedded_fields(code, "VK_BYTES")?
+        .ok_or_else(|| invalid("VK declaration not found".to_string()))?;
+    if declared != expected || embedded != expected {
+        return Err(invalid(format!(
+            "VK_LEN is {}, {} fields embedded, expected {}",
+            declared, embedded, expected
+        )));
+    }
+
+    // A router also embeds `VK_BYTES_1`, `VK_BYTES_2`, ... after the contract's own
+    for i in 1.. {
+        let Some(embedded) = embedded_fields(code, &format!("VK_BYTES_{}", i))? else {
+            break;
+        };
+        if embedded != expected {
+            return Err(invalid(format!(
+                "VK_BYTES_{} has {} fields embedded, expected {}",
+                i, embedded, expected
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Number of fields in the `const {name}: &[u8; VK_LEN * 32]` byte string,
+/// or `None` if the code declares no such constant
+fn embedded_fields(code: &str, name: &str) -> Result<Option<usize>, Error> {
+    let invalid = |msg: String| Error::new(ErrorKind::InvalidData, msg);
+
+    let vk_decl = format!("const {}: &[u8; VK_LEN * 32] = b\"", name);
+    let Some(decl_start) = code.find(&vk_decl) else {
+        return Ok(None);
+    };
+    let body_start = decl_start + vk_decl.len();
+    let body_end = body_start
+        + code[body_start..]
+            .find("\";")
+            .ok_or_else(|| invalid(format!("Unterminated {} declaration", name)))?;
+    let embedded_bytes = decode_escaped_bytes(&code[body_start..body_end]).len();
+
+    if !embedded_bytes.is_multiple_of(FIELD_SIZE) {
+        return Err(invalid(format!(
+            "{} {} bytes embedded, not a whole number of fields",
+            embedded_bytes, name
+        )));
+    }
+
+    Ok(Some(embedded_bytes / FIELD_SIZE))
+}
+
+/// Render the structured view of a VK (sizes, then every commitment as `{x, y}`)
+/// as pretty-printed JSON
+fn vk_info_json(vk: &honk_structs::VerificationKey) -> String {
+    let hex = |value: Fr| format!("0x{}", hex_encode_last_bytes(&to_bytes_be(value), 32));
+
+    let commitments: serde_json::Map<String, serde_json::Value> = vk
+        .named_commitments()
+        .into_iter()
+        .map(|(name, point)| (name.to_string(), serde_json::json!({ "x": hex(point.x), "y": hex(point.y) })))
+        .collect();
+
+    // A number where JSON readers can take it, a decimal string past u64
+    let size = |value: Fr| match u64::try_from(value) {
+        Ok(value) => serde_json::json!(value),
+        Err(_) => serde_json::json!(value.to_string()),
+    };
+    let info = serde_json::json!({
+        "circuit_size": size(vk.circuit_size),
+        "log_circuit_size": size(vk.log_circuit_size),
+        "public_inputs_size": size(vk.public_inputs_size),
+        "has_pairing_points": vk.has_pairing_points,
+        "commitments": commitments,
+    });
+    serde_json::to_string_pretty(&info).expect("JSON values always serialize")
+}
+
+/// Read a transcript dump: an object keyed by the `Transcript::challenges`
+/// names, each value a `0x` hex string, or an array of them for the alphas,
+/// gate and sumcheck challenges. The gate and sumcheck arrays need only their
+/// first `log_n` entries; missing padding is filled with zeros.
+fn transcript_from_json(json: &str, log_n: usize) -> Result<Transcript, Error> {
+    if log_n > CONST_PROOF_SIZE_LOG_N {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("log_n is {}, at most {} is supported", log_n, CONST_PROOF_SIZE_LOG_N),
+        ));
+    }
+    let dump: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+    let scalar = |name: &str| challenge_values(&dump, name, true).map(|values| values[0]);
+
+    let relation_parameters = RelationParameters::new(
+        scalar("eta")?,
+        scalar("eta_two")?,
+        scalar("eta_three")?,
+        scalar("beta")?,
+        scalar("gamma")?,
+    )
+    .with_public_input_delta(scalar("public_inputs_delta")?);
+
+    Ok(Transcript {
+        relation_parameters,
+        alphas: challenge_array(&dump, "alphas", NUMBER_OF_ALPHAS)?,
+        gate_challenges: challenge_array(&dump, "gate_challenges", log_n)?,
+        sumcheck_u_challenges: challenge_array(&dump, "sumcheck_u_challenges", log_n)?,
+        rho: scalar("rho")?,
+        gemini_r: scalar("gemini_r")?,
+        shplonk_nu: scalar("shplonk_nu")?,
+        shplonk_z: scalar("shplonk_z")?,
+    })
+}
+
+/// The array under `name`, of at least `required` and at most `N` entries,
+/// zero-padded to `N`
+fn challenge_array<const N: usize>(dump: &serde_json::Value, name: &str, required: usize) -> Result<[Fr; N], Error> {
+    let values = challenge_values(dump, name, false)?;
+    if values.len() < required || values.len() > N {
+        let expected = if required == N { N.to_string() } else { format!("{} to {}", required, N) };
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("{} has {} entries, expected {}", name, values.len(), expected),
+        ));
+    }
+    let mut array = [Fr::zero(); N];
+    array[..values.len()].copy_from_slice(&values);
+    Ok(array)
+}
+
+/// The hex values under `name`: one string if `scalar`, else an array of them
+fn challenge_values(dump: &serde_json::Value, name: &str, scalar: bool) -> Result<Vec<Fr>, Error> {
+    let invalid = |msg: String| Error::new(ErrorKind::InvalidData, msg);
+    let value = dump.get(name).ok_or_else(|| invalid(format!("Missing {}", name)))?;
+    let entries = if scalar {
+        core::slice::from_ref(value)
+    } else {
+        value
+            .as_array()
+            .ok_or_else(|| invalid(format!("{} is not an array", name)))?
+            .as_slice()
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .as_str()
+                .and_then(|hex| hex.strip_prefix("0x"))
+                .and_then(|hex| Fr::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| invalid(format!("{} is not a 0x-prefixed hex string", name)))
+        })
+        .collect()
+}
+
+/// `diff_transcripts`, plus the first differing index for the challenge arrays
+fn describe_divergence(ours: &Transcript, theirs: &Transcript, log_n: usize) -> Option<String> {
+    let name = diff_transcripts(ours, theirs, log_n)?;
+    let values = |transcript: &Transcript| {
+        transcript
+            .challenges_for(log_n)
+            .iter()
+            .find(|(challenge, _)| *challenge == name)
+            .map(|(_, values)| values.to_vec())
+            .expect("diff_transcripts names a challenge")
+    };
+    let (ours, theirs) = (values(ours), values(theirs));
+    if ours.len() == 1 {
+        return Some(format!("Transcripts diverge at {}", name));
+    }
+    let index = ours.iter().zip(&theirs).position(|(a, b)| a != b)?;
+    Some(format!("Transcripts diverge at {}[{}]", name, index))
+}
+
+// Helper function to turn a byte array into `\x..` byte-string escapes
+fn bytes_to_escaped_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("\\x{:02x}", b)).collect()
+}
+
+// Inverse of `bytes_to_escaped_string`, skipping the `\` line continuations
+fn decode_escaped_bytes(body: &str) -> Vec<u8> {
+    body.split("\\x")
+        .skip(1)
+        .filter_map(|chunk| chunk.get(..2))
+        .filter_map(|hex| u8::from_str_radix(hex, 16).ok())
+        .collect()
 }
 
 // Helper to show last N bytes as hex (for big-endian integers)
@@ -138,3 +674,368 @@ fn hex_encode_last_bytes(bytes: &[u8; 32], n: usize) -> String {
         .map(|b| format!("{:02x}", b))
         .collect::<String>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generated_verifier::curve::Q;
+    use generated_verifier::field::MODULUS;
+
+    fn sample_bytes(num_fields: usize) -> Vec<u8> {
+        (0..num_fields * FIELD_SIZE).map(|i| i as u8).collect()
+    }
+
+    fn sample_vk(num_fields: usize) -> VerificationKey {
+        parse_vk(&sample_bytes(num_fields), VkFormat::BbRaw).unwrap()
+    }
+
+    #[test]
+    fn generated_vk_matches_len() {
+        let vk = sample_vk(57);
+        let code = generate_contract_code(&vk, false);
+        assert!(code.contains("const VK_LEN: usize = 57;"));
+        assert!(code.contains("const VK_BYTES: &[u8; VK_LEN * 32] = b\""));
+        check_injected_vk(&code, 57).unwrap();
+    }
+
+    #[test]
+    fn embedded_vk_bytes_round_trip() {
+        let vk = sample_vk(57);
+        let code = generate_contract_code(&vk, false);
+
+        let start = code.find("VK_LEN * 32] = b\"").unwrap();
+        let end = start + code[start..].find("\";").unwrap();
+        assert_eq!(decode_escaped_bytes(&code[start..end]), vk.fields.concat());
+    }
+
+    #[test]
+    fn tampered_vk_len_is_rejected() {
+        let vk = sample_vk(57);
+        let code = generate_contract_code(&vk, false)
+            .replace("const VK_LEN: usize = 57;", "const VK_LEN: usize = 58;");
+        assert!(check_injected_vk(&code, 57).is_err());
+    }
+
+    fn fixture_vk() -> VerificationKey {
+        parse_vk(include_bytes!("../../generated_verifier/tests/fixtures/vk"), VkFormat::BbRaw).unwrap()
+    }
+
+    #[test]
+    fn vk_info_lists_every_commitment() {
+        let vk = fixture_vk().to_structured().unwrap();
+        let info: serde_json::Value = serde_json::from_str(&vk_info_json(&vk)).unwrap();
+        assert_eq!(info["circuit_size"], 32);
+        assert_eq!(info["log_circuit_size"], 5);
+        assert_eq!(info["public_inputs_size"], 1);
+        assert_eq!(info["has_pairing_points"], false);
+
+        let commitments = info["commitments"].as_object().unwrap();
+        let names: Vec<&str> = commitments.keys().map(String::as_str).collect();
+        let expected: Vec<&str> = vk.named_commitments().iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, expected);
+        assert_eq!(
+            commitments["lagrange_last"]["x"],
+            format!("0x{}", hex_encode_last_bytes(&to_bytes_be(vk.lagrange_last.x), 32))
+        );
+    }
+
+    #[test]
+    fn dropped_vk_field_is_rejected() {
+        let vk = sample_vk(57);
+        let code = generate_contract_code(&vk, false);
+        let last_field = format!("\\\n        {}", bytes_to_escaped_string(&vk.fields[56]));
+        let code = code.replacen(&last_field, "", 1);
+        assert!(check_injected_vk(&code, 57).is_err());
+    }
+
+    #[test]
+    fn le_raw_matches_bb_raw() {
+        let bytes = sample_bytes(57);
+        let reversed: Vec<u8> = bytes
+            .chunks_exact(FIELD_SIZE)
+            .flat_map(|field| field.iter().rev().copied())
+            .collect();
+
+        let le = parse_vk(&reversed, VkFormat::LeRaw).unwrap();
+        assert_eq!(le.fields, sample_vk(57).fields);
+    }
+
+    #[test]
+    fn field_prefixed_strips_count() {
+        let mut bytes = 57u32.to_be_bytes().to_vec();
+        bytes.extend(sample_bytes(57));
+        assert_eq!(parse_vk(&bytes, VkFormat::FieldPrefixed).unwrap().fields, sample_vk(57).fields);
+
+        bytes[3] = 58;
+        assert!(parse_vk(&bytes, VkFormat::FieldPrefixed).is_err());
+    }
+
+    #[test]
+    fn vk_format_flag() {
+        let args = Args::try_parse_from(["ink-generator", "--vk", "vk", "--output", "lib.rs"]).unwrap();
+        assert_eq!(args.vk_format, VkFormat::BbRaw);
+
+        let args = Args::try_parse_from([
+            "ink-generator", "--vk", "vk", "--output", "lib.rs", "--vk-format", "le-raw",
+        ])
+        .unwrap();
+        assert_eq!(args.vk_format, VkFormat::LeRaw);
+
+        assert!(Args::try_parse_from([
+            "ink-generator", "--vk", "vk", "--output", "lib.rs", "--vk-format", "be-raw",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn fixture_vk_reproduces_generated_verifier() {
+        // generated_verifier is this generator's output for its fixture VK, so
+        // its build and tests cover this output
+        let code = generate_contract_code(&fixture_vk(), false);
+        assert!(code == include_str!("../../generated_verifier/src/lib.rs"), "regenerate generated_verifier/src/lib.rs");
+    }
+
+    #[test]
+    fn fixture_vk_converts_to_structured() {
+        let vk = fixture_vk();
+        let structured = vk.to_structured().unwrap();
+        assert_eq!(structured.circuit_size, Fr::from(32));
+        assert_eq!(structured.public_inputs_size, Fr::one());
+        assert!(!structured.has_pairing_points);
+        // bb lists `qm` first and `lagrange_last` last, four limbs each after
+        // the 20 header fields
+        for (point, first) in [(structured.qm, 20), (structured.lagrange_last, 124)] {
+            let (x_0, x_1) = honk_structs::pack_coord(point.x);
+            let (y_0, y_1) = honk_structs::pack_coord(point.y);
+            let limbs = [x_0, x_1, y_0, y_1].map(to_bytes_be);
+            assert_eq!(limbs, vk.fields[first..first + 4]);
+        }
+
+        let short = VerificationKey { fields: vk.fields[1..].to_vec() };
+        assert!(short.to_structured().is_err());
+
+        let mut out_of_range = VerificationKey { fields: vk.fields.clone() };
+        out_of_range.fields[3] = to_bytes_be(Q);
+        assert!(out_of_range.to_structured().is_err());
+
+        // In range, but off the curve: only `self_test` catches it
+        let mut off_curve = VerificationKey { fields: vk.fields.clone() };
+        off_curve.fields[20][31] ^= 1;
+        assert!(off_curve.to_structured().unwrap_err().to_string().contains("self test"));
+    }
+
+    #[test]
+    fn cache_sections_only_with_flag() {
+        let vk = sample_vk(57);
+
+        let lean = generate_contract_code(&vk, false);
+        assert!(!lean.contains("Mapping<[u8; 32], bool>"));
+        assert!(!lean.contains("fn verify_cached"));
+        assert!(!lean.contains("%%"));
+
+        let cached = generate_contract_code(&vk, true);
+        assert!(cached.contains("verified: Mapping<[u8; 32], bool>,"));
+        assert!(cached.contains("pub fn verify_cached("));
+        assert!(cached.contains("pub fn clear_cache("));
+        assert_eq!(cached.matches("verified: Mapping::default(),").count(), 2);
+        assert!(!cached.contains("%%"));
+        check_injected_vk(&cached, 57).unwrap();
+
+        let args = Args::try_parse_from(["ink-generator", "--vk", "vk", "--output", "lib.rs", "--with-cache"]).unwrap();
+        assert!(args.with_cache);
+    }
+
+    /// Valid VKs told apart by their public input count
+    fn router_vks() -> Vec<VerificationKey> {
+        (1..=3u64)
+            .map(|inputs| {
+                let mut vk = fixture_vk();
+                vk.fields[1] = to_bytes_be(Fr::from(inputs));
+                vk
+            })
+            .collect()
+    }
+
+    #[test]
+    fn router_dispatches_on_circuit_id() {
+        let vks = router_vks();
+        check_router_vks(&vks).unwrap();
+
+        let code = generate_router_code(&vks);
+        assert!(code.contains("const VK_BYTES_2: &[u8; VK_LEN * 32] = b\""));
+        assert!(code.contains(
+            "const ROUTED_VKS: [[[u8; 32]; VK_LEN]; 2] = [split_vk_fields(VK_BYTES_1), split_vk_fields(VK_BYTES_2)];"
+        ));
+        assert!(code.contains("pub fn verify(\n            &self,\n            circuit_id: u32,"));
+        assert_eq!(code.matches("pub fn verify(\n").count(), 1);
+        assert!(!code.contains("mod tests"));
+        assert!(!code.contains("%%"));
+        check_injected_vk(&code, 128).unwrap();
+
+        // Drop the last field of VK_BYTES_2
+        let last_field = format!("\\\n        {}", bytes_to_escaped_string(&vks[2].fields[127]));
+        let at = code.rfind(&last_field).unwrap();
+        let code = format!("{}{}", &code[..at], &code[at + last_field.len()..]);
+        assert!(check_injected_vk(&code, 128).unwrap_err().to_string().starts_with("VK_BYTES_2 "));
+
+        // A single VK leaves nothing to route to past circuit 0
+        let code = generate_router_code(&vks[..1]);
+        assert!(code.contains("const ROUTED_VKS: [[[u8; 32]; VK_LEN]; 0] = [];"));
+    }
+
+    /// Copy `from` into `to`, leaving out build output
+    fn copy_crate(from: &std::path::Path, to: &std::path::Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            if entry.file_name() == "target" {
+                continue;
+            }
+            if entry.file_type().unwrap().is_dir() {
+                copy_crate(&entry.path(), &to.join(entry.file_name()));
+            } else {
+                fs::copy(entry.path(), to.join(entry.file_name())).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn router_contract_compiles() {
+        // generated_verifier with its lib.rs swapped for a router, checked with a
+        // target directory of its own so it doesn't wait on the crate's builds
+        let manifest_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let krate = std::env::temp_dir().join(format!("ink-generator-router-{}", std::process::id()));
+        copy_crate(&manifest_dir.join("../generated_verifier"), &krate);
+        fs::write(krate.join("src/lib.rs"), generate_router_code(&router_vks())).unwrap();
+
+        let output = std::process::Command::new(env!("CARGO"))
+            .args(["check", "--offline", "--lib", "--quiet"])
+            .current_dir(&krate)
+            .env("CARGO_TARGET_DIR", manifest_dir.join("target/router-check"))
+            .output()
+            .unwrap();
+        fs::remove_dir_all(&krate).unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    #[test]
+    fn router_rejects_mismatched_vks() {
+        assert!(check_router_vks(&[]).is_err());
+
+        let vk = fixture_vk();
+        let short = VerificationKey { fields: vk.fields[..127].to_vec() };
+        let err = check_router_vks(&[fixture_vk(), short]).unwrap_err();
+        assert!(err.to_string().contains("circuit 1 has 127 field elements"));
+
+        // Each routed VK gets the range checks and `self_test`
+        let mut out_of_range = fixture_vk();
+        out_of_range.fields[3] = to_bytes_be(Q);
+        let mut off_curve = fixture_vk();
+        off_curve.fields[20][31] ^= 1;
+        let mut too_large = fixture_vk();
+        too_large.fields[0] = to_bytes_be(Fr::one() << 30);
+        for bad in [out_of_range, off_curve, too_large] {
+            assert!(check_router_vks(&[fixture_vk(), bad]).unwrap_err().to_string().starts_with("Circuit 1: "));
+        }
+
+        assert!(Args::try_parse_from(["ink-generator", "--vks", "vks", "--output", "lib.rs"]).is_ok());
+        assert!(Args::try_parse_from(["ink-generator", "--vks", "vks", "--vk", "vk", "--output", "lib.rs"]).is_err());
+        assert!(Args::try_parse_from(["ink-generator", "--vks", "vks", "--output", "lib.rs", "--with-cache"]).is_err());
+        assert!(Args::try_parse_from(["ink-generator", "--output", "lib.rs"]).is_err());
+    }
+
+    /// A dump in the format `transcript_from_json` reads, the `i`th value set to `i`
+    /// but for `gamma` and `sumcheck_u_challenges[3]`, with `rounds` gate and
+    /// sumcheck challenges
+    fn transcript_dump(gamma: u64, sumcheck_3: u64, rounds: u64) -> serde_json::Value {
+        let hex = |value: u64| format!("0x{:x}", value);
+        let array = |start: u64, len: u64| (start..start + len).map(hex).collect::<Vec<String>>();
+        let mut sumcheck = array(59, rounds);
+        sumcheck[3] = hex(sumcheck_3);
+        serde_json::json!({
+            "eta": hex(0),
+            "eta_two": hex(1),
+            "eta_three": hex(2),
+            "beta": hex(3),
+            "gamma": hex(gamma),
+            "public_inputs_delta": hex(5),
+            "alphas": array(6, 25),
+            "gate_challenges": array(31, rounds),
+            "sumcheck_u_challenges": sumcheck,
+            "rho": hex(87),
+            "gemini_r": hex(88),
+            "shplonk_nu": hex(89),
+            "shplonk_z": hex(90),
+        })
+    }
+
+    #[test]
+    fn diff_transcript_names_first_divergence() {
+        let read = |dump: serde_json::Value, log_n| transcript_from_json(&dump.to_string(), log_n);
+
+        let ours = read(transcript_dump(4, 62, 28), 28).unwrap();
+        assert_eq!(ours.relation_parameters.gamma, Fr::from(4));
+        assert_eq!(ours.sumcheck_u_challenges[27], Fr::from(86));
+        assert_eq!(describe_divergence(&ours, &ours, 28), None);
+
+        let theirs = read(transcript_dump(0x44, 0, 28), 28).unwrap();
+        assert_eq!(describe_divergence(&ours, &theirs, 28).unwrap(), "Transcripts diverge at gamma");
+        let theirs = read(transcript_dump(4, 0, 28), 28).unwrap();
+        assert_eq!(
+            describe_divergence(&ours, &theirs, 28).unwrap(),
+            "Transcripts diverge at sumcheck_u_challenges[3]"
+        );
+
+        // A dump of only the log_n rounds the circuit uses matches a padded one
+        let short = read(transcript_dump(4, 62, 5), 5).unwrap();
+        assert_eq!(short.sumcheck_u_challenges[5], Fr::zero());
+        assert_eq!(describe_divergence(&ours, &short, 5), None);
+        assert_eq!(
+            describe_divergence(&ours, &short, 6).unwrap(),
+            "Transcripts diverge at gate_challenges[5]"
+        );
+
+        let err = read(transcript_dump(4, 62, 5), 6).unwrap_err();
+        assert!(err.to_string().contains("has 5 entries, expected 6 to 28"));
+        assert!(read(transcript_dump(4, 62, 29), 28).is_err());
+        assert!(read(transcript_dump(4, 62, 28), 29).is_err());
+        let mut not_hex = transcript_dump(4, 62, 28);
+        not_hex["gamma"] = serde_json::json!(4);
+        assert!(read(not_hex, 28).is_err());
+
+        let args = Args::try_parse_from([
+            "ink-generator", "diff-transcript", "--ours", "a.json", "--theirs", "b.json", "--log-n", "5",
+        ])
+        .unwrap();
+        assert!(matches!(args.command, Some(Command::DiffTranscript { log_n: 5, .. })));
+    }
+
+    #[test]
+    fn audit_reports_first_bad_proof_field() {
+        let vk = parse_vk(&[0u8; 4 * FIELD_SIZE], VkFormat::BbRaw).unwrap();
+        let mut proof = vec![0u8; 6 * FIELD_SIZE];
+        proof[2 * FIELD_SIZE..3 * FIELD_SIZE].copy_from_slice(&to_bytes_be(MODULUS));
+        proof[4 * FIELD_SIZE..5 * FIELD_SIZE].copy_from_slice(&[0xff; 32]);
+
+        let err = audit_ranges(&vk, &proof).unwrap_err();
+        assert!(err.to_string().contains("Proof field at byte offset 64 "));
+        assert!(audit_ranges(&vk, &proof[..2 * FIELD_SIZE]).is_ok());
+        assert!(audit_ranges(&vk, &proof[..FIELD_SIZE + 1]).is_err());
+    }
+
+    #[test]
+    fn audit_allows_base_field_coordinates() {
+        let vk_bytes = include_bytes!("../../generated_verifier/tests/fixtures/vk");
+        let mut vk = parse_vk(vk_bytes, VkFormat::BbRaw).unwrap();
+        let x = honk_structs::BB_VK_HEADER_FIELDS;
+
+        let (lo, hi) = honk_structs::pack_coord(MODULUS);
+        (vk.fields[x], vk.fields[x + 1]) = (to_bytes_be(lo), to_bytes_be(hi));
+        assert!(audit_ranges(&vk, &[]).is_ok());
+
+        let (lo, hi) = honk_structs::pack_coord(Q);
+        (vk.fields[x], vk.fields[x + 1]) = (to_bytes_be(lo), to_bytes_be(hi));
+        let err = audit_ranges(&vk, &[]).unwrap_err();
+        assert!(err.to_string().contains(&format!("VK field at byte offset {} ", x * FIELD_SIZE)));
+    }
+}